@@ -0,0 +1,156 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context};
+use itertools::Itertools;
+
+/// Accepted answers keyed by day and part, stored as a small TOML file
+///
+/// ```toml
+/// [day1]
+/// part1 = "55538"
+/// part2 = "54875"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+/// The answers file at the root of the repo
+pub fn default_answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    /// Days with at least one recorded answer, in order
+    pub fn days(&self) -> Vec<u8> {
+        self.answers.keys().map(|(day, _)| *day).dedup().collect()
+    }
+
+    /// Loads the answers, treating a missing file as having no answers recorded
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read answers from {}", path.display()))?;
+
+        contents
+            .parse()
+            .with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), anyhow::Error> {
+        std::fs::write(path, self.to_string())
+            .with_context(|| format!("Couldn't write answers to {}", path.display()))
+    }
+}
+
+fn parse_key(key: &str, prefix: &str) -> Option<u8> {
+    key.strip_prefix(prefix)?.parse::<u8>().ok()
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+        let mut day = None;
+
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                day = Some(
+                    parse_key(section.trim(), "day")
+                        .ok_or_else(|| anyhow!("Line {}: expected [dayN], got {line}", idx + 1))?,
+                );
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| anyhow!("Line {}: expected partN = answer", idx + 1))?;
+            let part = parse_key(key.trim(), "part")
+                .ok_or_else(|| anyhow!("Line {}: expected part1 or part2", idx + 1))?;
+            let day = day.ok_or_else(|| anyhow!("Line {}: answer outside a [dayN]", idx + 1))?;
+
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+
+            answers.record(day, part, value.to_string());
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut current_day = None;
+
+        for ((day, part), answer) in &self.answers {
+            if current_day != Some(*day) {
+                if current_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{day}]")?;
+                current_day = Some(*day);
+            }
+
+            writeln!(f, "part{part} = \"{answer}\"")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.record(9, 2, "-4".to_string());
+        answers.record(1, 1, "142".to_string());
+        answers.record(1, 2, "281".to_string());
+
+        let parsed = answers.to_string().parse::<Answers>().unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.days(), vec![1, 9]);
+    }
+
+    #[test]
+    fn unquoted_values_and_comments() {
+        let answers = "# Accepted answers\n[day6]\npart1 = 288\n"
+            .parse::<Answers>()
+            .unwrap();
+        assert_eq!(answers.get(6, 1), Some("288"));
+        assert_eq!(answers.get(6, 2), None);
+    }
+
+    #[test]
+    fn answer_outside_day() {
+        assert!("part1 = 1".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use std::{collections::BTreeMap, fmt::Display, path::Path, str::FromStr, time::Duration};
+
+use anyhow::{anyhow, Context};
+
+use crate::{parse::ParseMode, runner::Day};
+
+/// Summary of repeated timings of one stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises the timings, where an even number of runs uses the mean of the middle two
+    pub fn from_timings(timings: &[Duration]) -> Option<Self> {
+        let mut timings = timings.to_vec();
+        timings.sort();
+
+        let len = timings.len();
+        let median = match len {
+            0 => return None,
+            _ if len.is_multiple_of(2) => (timings[len / 2 - 1] + timings[len / 2]) / 2,
+            _ => timings[len / 2],
+        };
+
+        Some(Self {
+            min: timings[0],
+            median,
+            max: timings[len - 1],
+        })
+    }
+}
+
+/// A timed step of a day's solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            _ => s
+                .strip_prefix("part")
+                .and_then(|part| part.parse::<u8>().ok())
+                .map(Stage::Part)
+                .ok_or_else(|| anyhow!("Unknown stage {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub stages: Vec<(Stage, Stats)>,
+}
+
+/// Runs a day `runs` times, timing the parsing and each part separately
+pub fn bench(
+    day: &Day,
+    puzzle_input: &str,
+    parts: &[u8],
+    mode: ParseMode,
+    runs: usize,
+) -> Result<DayBench, anyhow::Error> {
+    let mut parse_timings = Vec::with_capacity(runs);
+    let mut part_timings = vec![Vec::with_capacity(runs); parts.len()];
+
+    for _ in 0..runs {
+        let run = day.run(puzzle_input, parts, mode)?;
+        parse_timings.push(run.parse_elapsed);
+
+        for (timings, part) in part_timings.iter_mut().zip(&run.parts) {
+            timings.push(part.elapsed);
+        }
+    }
+
+    let mut stages = vec![];
+    if let Some(stats) = Stats::from_timings(&parse_timings) {
+        stages.push((Stage::Parse, stats));
+    }
+    for (part, timings) in parts.iter().zip(&part_timings) {
+        if let Some(stats) = Stats::from_timings(timings) {
+            stages.push((Stage::Part(*part), stats));
+        }
+    }
+
+    Ok(DayBench {
+        day: day.number,
+        stages,
+    })
+}
+
+/// Median timings of an earlier benchmark to compare against, one `day stage nanoseconds`
+/// per line
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+
+    /// Adds the medians of a benchmark, replacing any earlier ones for the same day
+    pub fn record(&mut self, bench: &DayBench) {
+        for (stage, stats) in &bench.stages {
+            self.medians.insert((bench.day, *stage), stats.median);
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read baseline from {}", path.display()))?
+            .parse()
+            .with_context(|| format!("Invalid baseline {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), anyhow::Error> {
+        std::fs::write(path, self.to_string())
+            .with_context(|| format!("Couldn't write baseline to {}", path.display()))
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut baseline = Self::default();
+
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut iter = line.split_whitespace();
+            let (Some(day), Some(stage), Some(nanos), None) =
+                (iter.next(), iter.next(), iter.next(), iter.next())
+            else {
+                anyhow::bail!("Line {}: expected <day> <stage> <nanoseconds>", idx + 1);
+            };
+
+            let day = day
+                .parse::<u8>()
+                .with_context(|| format!("Line {}: invalid day", idx + 1))?;
+            let stage = stage.parse::<Stage>()?;
+            let nanos = nanos
+                .parse::<u64>()
+                .with_context(|| format!("Line {}: invalid duration", idx + 1))?;
+
+            baseline
+                .medians
+                .insert((day, stage), Duration::from_nanos(nanos));
+        }
+
+        Ok(baseline)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day stage median_nanoseconds")?;
+        for ((day, stage), median) in &self.medians {
+            writeln!(f, "{day} {stage} {}", median.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_odd_and_even_runs() {
+        let ms = Duration::from_millis;
+
+        let stats = Stats::from_timings(&[ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(5));
+
+        let stats = Stats::from_timings(&[ms(4), ms(1), ms(2), ms(8)]).unwrap();
+        assert_eq!(stats.median, ms(3));
+
+        assert_eq!(Stats::from_timings(&[]), None);
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let stats = Stats {
+            min: Duration::from_nanos(1),
+            median: Duration::from_nanos(2),
+            max: Duration::from_nanos(3),
+        };
+        let mut baseline = Baseline::default();
+        baseline.record(&DayBench {
+            day: 5,
+            stages: vec![(Stage::Parse, stats), (Stage::Part(2), stats)],
+        });
+
+        let parsed = baseline.to_string().parse::<Baseline>().unwrap();
+        assert_eq!(parsed, baseline);
+        assert_eq!(parsed.get(5, Stage::Part(2)), Some(Duration::from_nanos(2)));
+        assert_eq!(parsed.get(5, Stage::Part(1)), None);
+    }
+}
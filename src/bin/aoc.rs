@@ -0,0 +1,575 @@
+use anyhow::{anyhow, bail, Context};
+
+use std::path::{Path, PathBuf};
+
+use aoc2023::{
+    answers::{default_answers_path, Answers},
+    bench::{bench, Baseline, DayBench},
+    day05::{traces_to_json, Day05},
+    day07::{Day07, Hand, Report, RuleSet},
+    input::{read_input, InputSource},
+    parse::{ParseError, ParseMode, Parser},
+    runner::{to_json, DayRun},
+    scaffold::new_day,
+    Solution,
+};
+
+const USAGE: &str =
+    "Usage: aoc [COMMAND] [--all | --day <DAYS> | <DAYS>...] [--part <1|2>] [--input <PATH>]
+           [--format <table|json|csv>] [--strict | --lenient]
+
+Commands:
+  run     Run the selected days and print their answers (default)
+  record  Run the selected days, or all days, and record their answers as accepted
+  verify  Re-run the selected days, or all recorded days, and compare against the
+          recorded answers. Exits with a non-zero code on any mismatch
+  bench   Time the parsing and each part of the selected days over repeated runs
+  new-day Generate a module for a day, with example tests and an empty input, and
+          register it with the runner
+  trace   Show the path of day 5 seeds through every category, and the entry used
+          at each step
+  report  Show how day 7's hands rank: the number of each type, and each hand's rank,
+          bid, winnings and the hand its wild cards were promoted to
+
+Trace options:
+  --seed <SEEDS>          Seeds to trace, like 79 or 79,14. Defaults to the seed that
+                          reaches the lowest location in each selected part
+
+Report options:
+  --rules <RULES>         jacks, jokers, or the card labels from weakest to strongest
+                          with any wild labels after a slash, like 23456789TJQKA/2.
+                          Defaults to the rules of each selected part
+
+Bench options:
+  --runs <N>              Number of runs per day (default 10)
+  --baseline <PATH>       Compare the median timings against a saved baseline
+  --save-baseline <PATH>  Save the median timings as a baseline
+
+--strict fails on the first line that can't be parsed. --lenient, the default, leaves it out
+and reports each skipped line and why on stderr
+--format json prints the answers of run and record as JSON, with durations in nanoseconds,
+or the steps of trace. --format csv prints the hands of report as CSV
+
+DAYS is a single day (5), a list (1,3,9) or a range (2-6)
+PATH is a file, or - to read from stdin, and can only be given for a single day.
+Inputs are otherwise read from dayN.txt in AOC_INPUT_DIR, or the repo's inputs directory.
+Answers are stored in answers.toml in the repo, or the file given by --answers <PATH>";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Run,
+    Record,
+    Verify,
+    Bench,
+    NewDay,
+    Trace,
+    Report,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+struct Args {
+    command: Command,
+    format: Format,
+    mode: ParseMode,
+    days: Vec<u8>,
+    parts: Vec<u8>,
+    input: InputSource,
+    answers: PathBuf,
+    runs: usize,
+    baseline: Option<PathBuf>,
+    save_baseline: Option<PathBuf>,
+    seeds: Vec<u64>,
+    rules: Option<RuleSet>,
+}
+
+/// Parses a day selection like "5", "1,3,9" or "2-6"
+fn parse_days(value: &str) -> Result<Vec<u8>, anyhow::Error> {
+    let mut days = vec![];
+
+    for item in value.split(',').filter(|item| !item.is_empty()) {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let start = start.parse::<u8>().context("Invalid range start")?;
+                let end = end.parse::<u8>().context("Invalid range end")?;
+                days.extend(start..=end);
+            }
+            None => days.push(item.parse::<u8>().context("Invalid day")?),
+        }
+    }
+
+    Ok(days)
+}
+
+fn parse_args() -> Result<Args, anyhow::Error> {
+    let mut days = vec![];
+    let mut parts = vec![1, 2];
+    let mut all = false;
+    let mut input = InputSource::Default;
+    let mut answers = default_answers_path();
+    let mut format = Format::Table;
+    let mut mode = ParseMode::Lenient;
+    let mut runs = 10;
+    let mut baseline = None;
+    let mut save_baseline = None;
+    let mut seeds = vec![];
+    let mut rules = None;
+
+    let mut args = std::env::args().skip(1).peekable();
+    let command = match args.peek().map(String::as_str) {
+        Some("run") => Some(Command::Run),
+        Some("record") => Some(Command::Record),
+        Some("verify") => Some(Command::Verify),
+        Some("bench") => Some(Command::Bench),
+        Some("new-day") => Some(Command::NewDay),
+        Some("trace") => Some(Command::Trace),
+        Some("report") => Some(Command::Report),
+        _ => None,
+    };
+    // The command is optional, and only consumed if one was given
+    let command = match command {
+        Some(command) => {
+            args.next();
+            command
+        }
+        None => Command::Run,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" | "-a" => all = true,
+            "--day" | "-d" => {
+                let value = args.next().ok_or_else(|| anyhow!("--day needs a value"))?;
+                days.extend(parse_days(&value)?);
+            }
+            "--part" | "-p" => {
+                let value = args.next().ok_or_else(|| anyhow!("--part needs a value"))?;
+                parts = match value.as_str() {
+                    "1" => vec![1],
+                    "2" => vec![2],
+                    _ => bail!("Part must be 1 or 2, got {value}"),
+                };
+            }
+            "--input" | "-i" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--input needs a value"))?;
+                input = InputSource::from(value.as_str());
+            }
+            "--answers" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--answers needs a value"))?;
+                answers = PathBuf::from(value);
+            }
+            "--format" | "-f" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--format needs a value"))?;
+                format = match value.as_str() {
+                    "table" => Format::Table,
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    _ => bail!("Format must be table, json or csv, got {value}"),
+                };
+            }
+            "--strict" => mode = ParseMode::Strict,
+            "--lenient" => mode = ParseMode::Lenient,
+            "--runs" => {
+                let value = args.next().ok_or_else(|| anyhow!("--runs needs a value"))?;
+                runs = value.parse::<usize>().context("Invalid number of runs")?;
+                if runs == 0 {
+                    bail!("--runs must be at least 1");
+                }
+            }
+            "--baseline" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--baseline needs a value"))?;
+                baseline = Some(PathBuf::from(value));
+            }
+            "--save-baseline" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--save-baseline needs a value"))?;
+                save_baseline = Some(PathBuf::from(value));
+            }
+            "--seed" => {
+                let value = args.next().ok_or_else(|| anyhow!("--seed needs a value"))?;
+                for seed in value.split(',').filter(|seed| !seed.is_empty()) {
+                    seeds.push(seed.parse::<u64>().context("Invalid seed")?);
+                }
+            }
+            "--rules" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--rules needs a value"))?;
+                rules = Some(value.parse::<RuleSet>().context("Invalid rules")?);
+            }
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            _ if !arg.starts_with('-') => days.extend(parse_days(&arg)?),
+            _ => bail!("Unknown argument {arg}\n\n{USAGE}"),
+        }
+    }
+
+    if all || (days.is_empty() && command == Command::Record) {
+        days = aoc2023::DAYS.iter().map(|day| day.number).collect();
+    }
+
+    if days.is_empty() && command == Command::Verify {
+        days = Answers::load(&answers)?.days();
+    }
+
+    if command == Command::Trace {
+        if days.iter().any(|day| *day != Day05::DAY) {
+            bail!("trace is only available for day 5");
+        }
+        days = vec![Day05::DAY];
+    }
+
+    if command == Command::Report {
+        if days.iter().any(|day| *day != Day07::DAY) {
+            bail!("report is only available for day 7");
+        }
+        days = vec![Day07::DAY];
+    }
+
+    if days.is_empty() {
+        bail!("No days selected\n\n{USAGE}");
+    }
+
+    days.sort();
+    days.dedup();
+
+    if format == Format::Json && !matches!(command, Command::Run | Command::Record | Command::Trace)
+    {
+        bail!("--format json is only supported by run, record and trace");
+    }
+
+    if format == Format::Csv && command != Command::Report {
+        bail!("--format csv is only supported by report");
+    }
+
+    if command == Command::NewDay && days.len() != 1 {
+        bail!("new-day needs a single day");
+    }
+
+    if input != InputSource::Default && days.len() > 1 {
+        bail!("--input can only be used when running a single day");
+    }
+
+    Ok(Args {
+        command,
+        format,
+        mode,
+        days,
+        parts,
+        input,
+        answers,
+        runs,
+        baseline,
+        save_baseline,
+        seeds,
+        rules,
+    })
+}
+
+fn print_runs(runs: &[DayRun], format: Format) {
+    match format {
+        Format::Table => print_table(runs),
+        Format::Json => println!("{}", to_json(runs)),
+        Format::Csv => unreachable!("Only reports are printed as CSV"),
+    }
+}
+
+fn print_table(runs: &[DayRun]) {
+    println!(
+        "{:>3}  {:>4}  {:>20}  {:>12}",
+        "Day", "Part", "Answer", "Elapsed"
+    );
+
+    for run in runs {
+        for part in &run.parts {
+            println!(
+                "{:>3}  {:>4}  {:>20}  {:>12}",
+                run.day,
+                part.part,
+                part.answer,
+                format!("{:.2?}", part.elapsed)
+            );
+        }
+    }
+}
+
+/// Reports the lines left out in lenient mode, with the reason and the line itself
+fn print_skipped(day: u8, skipped: &[ParseError], puzzle_input: &str) {
+    if skipped.is_empty() {
+        return;
+    }
+
+    let lines = puzzle_input.lines().collect::<Vec<_>>();
+    eprintln!("Day {day} skipped {} line(s):", skipped.len());
+    for err in skipped {
+        eprintln!("  {err}");
+        if let Some(line) = lines.get(err.line - 1) {
+            eprintln!("    | {line}");
+        }
+    }
+}
+
+/// Prints the path of the chosen seeds through day 5's almanac, or of the seeds that reach
+/// the lowest location in the selected parts
+fn print_traces(args: &Args) -> Result<(), anyhow::Error> {
+    let puzzle_input = read_input(Day05::DAY, &args.input)?;
+    let mut parser = Parser::new(Day05::DAY, &puzzle_input, args.mode);
+    let almanac = Day05::parse_with(&mut parser)?;
+    print_skipped(Day05::DAY, parser.skipped(), &puzzle_input);
+
+    let mut seeds = args.seeds.clone();
+    if seeds.is_empty() {
+        for part in &args.parts {
+            seeds.extend(match part {
+                1 => almanac.lowest_location_seed(),
+                _ => almanac.lowest_location_seed_from_seed_range(),
+            });
+        }
+        seeds.dedup();
+    }
+
+    let traces = seeds
+        .iter()
+        .map(|seed| almanac.trace(*seed))
+        .collect::<Vec<_>>();
+
+    match args.format {
+        Format::Table => traces.iter().for_each(|trace| println!("{trace}")),
+        Format::Json => println!("{}", traces_to_json(&traces)),
+        Format::Csv => unreachable!("Only reports are printed as CSV"),
+    }
+
+    Ok(())
+}
+
+/// Prints how day 7's hands rank under the chosen rules, or under the rules of each selected
+/// part
+fn print_reports(args: &Args) -> Result<(), anyhow::Error> {
+    let puzzle_input = read_input(Day07::DAY, &args.input)?;
+
+    let rule_sets = match &args.rules {
+        Some(rules) => vec![rules.clone()],
+        None => args
+            .parts
+            .iter()
+            .map(|part| match part {
+                1 => RuleSet::jacks(),
+                _ => RuleSet::jokers(),
+            })
+            .collect(),
+    };
+
+    for (idx, rules) in rule_sets.iter().enumerate() {
+        // Custom rules can have a different deck, so the hands are parsed for each rule set
+        let mut parser = Parser::new(Day07::DAY, &puzzle_input, args.mode);
+        let hands = parser.lines(&puzzle_input, |line| Hand::parse(line, rules))?;
+        // The puzzle's rule sets share a deck, so their skipped lines are only shown once
+        if idx == 0 {
+            print_skipped(Day07::DAY, parser.skipped(), &puzzle_input);
+        }
+        let report = Report::new(&hands, rules);
+
+        match args.format {
+            Format::Table => {
+                if idx > 0 {
+                    println!();
+                }
+                print!("{report}");
+            }
+            // One header for all rule sets, which the rules column tells apart
+            Format::Csv => {
+                let csv = report.to_csv();
+                let rows = if idx > 0 {
+                    csv.split_once('\n').map_or("", |(_, rows)| rows)
+                } else {
+                    &csv
+                };
+                print!("{rows}");
+            }
+            Format::Json => unreachable!("Reports aren't printed as JSON"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints each answer next to the recorded one, returning whether they all match
+fn print_verify_table(runs: &[DayRun], answers: &Answers) -> bool {
+    println!(
+        "{:>3}  {:>4}  {:>20}  {:>20}  {:>12}  Status",
+        "Day", "Part", "Answer", "Expected", "Elapsed"
+    );
+
+    let mut all_match = true;
+    for run in runs {
+        for part in &run.parts {
+            let expected = answers.get(run.day, part.part);
+            let status = match expected {
+                Some(expected) if expected == part.answer => "ok",
+                Some(_) => {
+                    all_match = false;
+                    "MISMATCH"
+                }
+                None => "unrecorded",
+            };
+
+            println!(
+                "{:>3}  {:>4}  {:>20}  {:>20}  {:>12}  {}",
+                run.day,
+                part.part,
+                part.answer,
+                expected.unwrap_or("-"),
+                format!("{:.2?}", part.elapsed),
+                status
+            );
+        }
+    }
+
+    all_match
+}
+
+/// Prints the timings of each stage, with the change in median if there is a baseline
+fn print_bench_table(benches: &[DayBench], baseline: Option<&Baseline>) {
+    println!(
+        "{:>3}  {:>5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>8}",
+        "Day", "Stage", "Min", "Median", "Max", "Baseline", "Change"
+    );
+
+    for bench in benches {
+        for (stage, stats) in &bench.stages {
+            let previous = baseline.and_then(|baseline| baseline.get(bench.day, *stage));
+            let (previous, change) = match previous {
+                Some(previous) if !previous.is_zero() => {
+                    let change = stats.median.as_secs_f64() / previous.as_secs_f64() - 1.0;
+                    (
+                        format!("{previous:.2?}"),
+                        format!("{:+.1}%", change * 100.0),
+                    )
+                }
+                _ => ("-".to_string(), "-".to_string()),
+            };
+
+            println!(
+                "{:>3}  {:>5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>8}",
+                bench.day,
+                stage,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+                previous,
+                change
+            );
+        }
+    }
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    let args = parse_args()?;
+
+    if args.command == Command::NewDay {
+        let day = args.days[0];
+        if !(1..=25).contains(&day) {
+            bail!("Days run from 1 to 25, got {day}");
+        }
+
+        let new_day = new_day(Path::new(env!("CARGO_MANIFEST_DIR")), day)?;
+        println!("Created {}", new_day.module.display());
+        if let Some(input) = &new_day.input {
+            println!("Created {}", input.display());
+        }
+        println!("Registered day {day} in src/lib.rs");
+
+        return Ok(());
+    }
+
+    if args.command == Command::Trace {
+        return print_traces(&args);
+    }
+
+    if args.command == Command::Report {
+        return print_reports(&args);
+    }
+
+    let mut days = vec![];
+    for number in &args.days {
+        let day = aoc2023::day(*number).ok_or_else(|| anyhow!("Day {number} isn't solved"))?;
+        days.push((day, read_input(*number, &args.input)?));
+    }
+
+    if args.command == Command::Bench {
+        let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+
+        let benches = days
+            .iter()
+            .map(|(day, puzzle_input)| bench(day, puzzle_input, &args.parts, args.mode, args.runs))
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+        print_bench_table(&benches, baseline.as_ref());
+
+        if let Some(path) = &args.save_baseline {
+            // Days that weren't benchmarked this time keep their old baseline
+            let mut saved = if path.exists() {
+                Baseline::load(path)?
+            } else {
+                Baseline::default()
+            };
+            benches.iter().for_each(|bench| saved.record(bench));
+            saved.save(path)?;
+            println!("Saved baseline to {}", path.display());
+        }
+
+        return Ok(());
+    }
+
+    let runs = days
+        .iter()
+        .map(|(day, puzzle_input)| day.run(puzzle_input, &args.parts, args.mode))
+        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+    for (run, (_, puzzle_input)) in runs.iter().zip(&days) {
+        print_skipped(run.day, &run.skipped, puzzle_input);
+    }
+
+    match args.command {
+        Command::Run | Command::Bench | Command::NewDay | Command::Trace | Command::Report => {
+            print_runs(&runs, args.format)
+        }
+        Command::Record => {
+            let mut answers = Answers::load(&args.answers)?;
+            for run in &runs {
+                for part in run.parts.iter().filter(|part| part.answer != "unsolved") {
+                    answers.record(run.day, part.part, part.answer.clone());
+                }
+            }
+            answers.save(&args.answers)?;
+
+            print_runs(&runs, args.format);
+            eprintln!("Recorded answers in {}", args.answers.display());
+        }
+        Command::Verify => {
+            let answers = Answers::load(&args.answers)?;
+            if !print_verify_table(&runs, &answers) {
+                eprintln!("Some answers don't match {}", args.answers.display());
+                std::process::exit(1);
+            }
+        }
+    }
+
+    Ok(())
+}
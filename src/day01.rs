@@ -0,0 +1,174 @@
+use crate::{
+    parse::{ParseError, Parser},
+    Solution,
+};
+
+pub struct Day01;
+
+fn locate_number(input: &str) -> Vec<(usize, usize)> {
+    let numbers = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+    let mut indices = vec![];
+
+    for number in numbers {
+        let number_str = format!("{}", number);
+
+        // Find first occurence
+        if let Some(idx) = input.find(&number_str) {
+            indices.push((idx, number))
+        }
+
+        // Find last occurence
+        if let Some(idx) = input.rfind(&number_str) {
+            indices.push((idx, number))
+        }
+    }
+
+    indices
+}
+
+fn locate_number_literals(input: &str) -> Vec<(usize, usize)> {
+    let number_pairs = vec![
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+    ];
+
+    let mut indices: Vec<(usize, usize)> = vec![];
+
+    for (s, n) in number_pairs {
+        // Find first occurence
+        if let Some(idx) = input.find(s) {
+            indices.push((idx, n))
+        }
+
+        // Find last occurence
+        if let Some(idx) = input.rfind(s) {
+            indices.push((idx, n))
+        }
+    }
+
+    indices
+}
+
+pub fn sum_first_and_last_digit(input: &str, find_literal_numbers: bool) -> Option<usize> {
+    let mut indices = locate_number(input);
+
+    // Puzzle solution for part 1 and 2 differ based on whether you parse
+    // written numbers
+    if find_literal_numbers {
+        let literal_indices = locate_number_literals(input);
+        indices.extend(literal_indices);
+    }
+
+    indices.sort();
+    let (_, first_number) = indices.first()?;
+    let (_, last_number) = indices.last()?;
+
+    let num_string = format!("{}{}", first_number, last_number);
+
+    num_string.parse::<usize>().ok()
+}
+
+/// Calibration lines only contain letters and digits
+fn parse_calibration_line(line: &str) -> Result<String, ParseError> {
+    match line
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_alphanumeric())
+    {
+        Some((idx, c)) => Err(ParseError::at(
+            line,
+            &line[idx..idx + c.len_utf8()],
+            "a letter or digit",
+        )),
+        None => Ok(line.to_string()),
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        parser.lines(parser.input(), parse_calibration_line)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input
+            .iter()
+            .filter_map(|line| sum_first_and_last_digit(line, false))
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input
+            .iter()
+            .filter_map(|line| sum_first_and_last_digit(line, true))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseMode;
+
+    const EXAMPLE_PART1: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const EXAMPLE_PART2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day01::parse(EXAMPLE_PART1, ParseMode::Strict).unwrap();
+        assert_eq!(Day01::part1(&input), 142);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day01::parse(EXAMPLE_PART2, ParseMode::Strict).unwrap();
+        assert_eq!(Day01::part2(&input), 281);
+    }
+
+    #[test]
+    fn overlapping_spelled_digits() {
+        assert_eq!(sum_first_and_last_digit("twone", true), Some(21));
+        assert_eq!(sum_first_and_last_digit("eighthree", true), Some(83));
+        assert_eq!(sum_first_and_last_digit("sevenine", true), Some(79));
+        assert_eq!(sum_first_and_last_digit("twone", false), None);
+    }
+
+    #[test]
+    fn strict_mode_rejects_punctuation() {
+        let err = Day01::parse("1abc2\n3x-4\n", ParseMode::Strict).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (1, 2, 3));
+        assert_eq!(err.text, "-");
+
+        let input = Day01::parse("1abc2\n3x-4\n", ParseMode::Lenient).unwrap();
+        assert_eq!(Day01::part1(&input), 12);
+    }
+
+    #[test]
+    fn single_digit_is_both_first_and_last() {
+        assert_eq!(sum_first_and_last_digit("treb7uchet", false), Some(77));
+        assert_eq!(sum_first_and_last_digit("xfivex", true), Some(55));
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    parse::{ParseError, Parser},
+    Solution,
+};
+
+pub struct Day02;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Blue,
+}
+
+impl TryFrom<&str> for Colour {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "red" => Ok(Self::Red),
+            "blue" => Ok(Self::Blue),
+            "green" => Ok(Self::Green),
+            _ => Err(ParseError::new(value, "red, green or blue")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CubeSet {
+    colour: Colour,
+    amount: usize,
+}
+
+impl TryFrom<&str> for CubeSet {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (amount_str, colour_str) = value
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(value, "<amount> <colour>"))?;
+
+        let amount = amount_str
+            .parse::<usize>()
+            .map_err(|_| ParseError::at(value, amount_str, "a cube amount"))?;
+        let colour = Colour::try_from(colour_str).map_err(|err| err.within(value, colour_str))?;
+
+        Ok(CubeSet { amount, colour })
+    }
+}
+
+#[derive(Debug)]
+pub struct Round {
+    sets: Vec<CubeSet>,
+}
+
+impl TryFrom<&str> for Round {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let iter = value.split(", ");
+
+        let sets = iter
+            .map(|set_str| CubeSet::try_from(set_str).map_err(|err| err.within(value, set_str)))
+            .collect::<Result<Vec<CubeSet>, _>>()?;
+
+        Ok(Round { sets })
+    }
+}
+
+impl Round {
+    pub fn max_seen_of(&self, colour: &Colour) -> usize {
+        self.sets
+            .iter()
+            .filter(|set| &set.colour == colour)
+            .map(|set| set.amount)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: usize,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    fn max_seen_of(&self, colour: &Colour) -> usize {
+        self.rounds
+            .iter()
+            .map(|round| round.max_seen_of(colour))
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn minimum_set_power(&self) -> usize {
+        let max_red = self.max_seen_of(&Colour::Red);
+        let max_blue = self.max_seen_of(&Colour::Blue);
+        let max_green = self.max_seen_of(&Colour::Green);
+
+        max_red * max_green * max_blue
+    }
+
+    pub fn is_possible_with(&self, contents: &BagContents) -> bool {
+        self.max_seen_of(&Colour::Red) <= contents.red
+            && self.max_seen_of(&Colour::Green) <= contents.green
+            && self.max_seen_of(&Colour::Blue) <= contents.blue
+    }
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let trimmed_input = value
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::new(value, "\"Game \""))?;
+        let (id_str, rounds_str) = trimmed_input
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(value, trimmed_input, "<id>: <rounds>"))?;
+
+        let id = id_str
+            .parse::<usize>()
+            .map_err(|_| ParseError::at(value, id_str, "a game ID"))?;
+
+        let rounds = rounds_str
+            .split("; ")
+            .map(|round_str| Round::try_from(round_str).map_err(|err| err.within(value, round_str)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Game { id, rounds })
+    }
+}
+
+pub struct BagContents {
+    pub red: usize,
+    pub green: usize,
+    pub blue: usize,
+}
+
+impl BagContents {
+    pub fn new(red: usize, green: usize, blue: usize) -> Self {
+        BagContents { red, green, blue }
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        parser.lines(parser.input(), Game::try_from)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let contents = BagContents::new(12, 13, 14);
+
+        input
+            .iter()
+            .filter(|game| game.is_possible_with(&contents))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.iter().map(|game| game.minimum_set_power()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseMode;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day02::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day02::part1(&input), 8);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day02::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day02::part2(&input), 2286);
+    }
+
+    #[test]
+    fn invalid_colour_location() {
+        let input = "Game 1: 3 blue\nGame 2: 1 red, 2 pink; 1 blue\n";
+
+        let err = Day02::parse(input, ParseMode::Strict).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 18));
+        assert_eq!(err.text, "pink");
+
+        let games = Day02::parse(input, ParseMode::Lenient).unwrap();
+        assert_eq!(games.len(), 1);
+    }
+}
@@ -1,14 +1,21 @@
 use itertools::Itertools;
 
+use crate::{
+    parse::{ParseError, Parser},
+    Solution,
+};
+
+pub struct Day03;
+
 #[derive(Debug, Clone, Copy)]
-enum SchematicContent {
+pub enum SchematicContent {
     Number(usize),
     Symbol(char),
     Empty,
 }
 
 #[derive(Debug)]
-struct SchematicItem {
+pub struct SchematicItem {
     content: SchematicContent,
     row: usize,
     start_col: usize,
@@ -63,20 +70,27 @@ impl SchematicItem {
     }
 }
 
+/// The engine schematic, with the items split into numbers and symbols
+#[derive(Debug)]
+pub struct Schematic {
+    number_items: Vec<SchematicItem>,
+    symbol_items: Vec<SchematicItem>,
+}
+
 fn parse_schematic_content(symbol: &str) -> Option<SchematicContent> {
     if symbol.is_empty() {
         return None;
     }
 
-    if let Some(number) = usize::from_str_radix(symbol, 10).ok() {
+    if let Ok(number) = symbol.parse::<usize>() {
         return Some(SchematicContent::Number(number));
     }
 
-    if !symbol.is_empty() && !symbol.contains(".") {
+    if !symbol.contains('.') {
         return Some(SchematicContent::Symbol(symbol.chars().next().unwrap()));
     }
 
-    return Some(SchematicContent::Empty);
+    Some(SchematicContent::Empty)
 }
 
 fn parse_initial_schematic_item(symbol: &str, row: usize, col: usize) -> Option<SchematicItem> {
@@ -94,7 +108,7 @@ fn parse_line(line: &str, row: usize) -> Vec<SchematicItem> {
             // and need to be combined into one
             (SchematicContent::Number(xval), SchematicContent::Number(yval)) => {
                 let number_str = format!("{}{}", xval, yval);
-                match usize::from_str_radix(&number_str, 10) {
+                match number_str.parse::<usize>() {
                     Ok(number) => Ok(SchematicItem {
                         row,
                         start_col: lhs.start_col,
@@ -109,13 +123,13 @@ fn parse_line(line: &str, row: usize) -> Vec<SchematicItem> {
         .collect()
 }
 
-fn part1(number_items: &Vec<&SchematicItem>, symbol_items: &Vec<&SchematicItem>) -> usize {
+fn part1(number_items: &[SchematicItem], symbol_items: &[SchematicItem]) -> usize {
     number_items
         .iter()
         .filter(|item| {
             symbol_items
                 .iter()
-                .any(|symbol_item| item.is_adjacent_to_symbol(&symbol_item))
+                .any(|symbol_item| item.is_adjacent_to_symbol(symbol_item))
         })
         .map(|item| match item.content {
             SchematicContent::Number(val) => val,
@@ -124,14 +138,14 @@ fn part1(number_items: &Vec<&SchematicItem>, symbol_items: &Vec<&SchematicItem>)
         .sum()
 }
 
-fn part2(number_items: &Vec<&SchematicItem>, symbol_items: &Vec<&SchematicItem>) -> usize {
+fn part2(number_items: &[SchematicItem], symbol_items: &[SchematicItem]) -> usize {
     symbol_items
         .iter()
         .filter_map(|item| {
             let adjacent_numbers = number_items
                 .iter()
                 .filter_map(|number_item| {
-                    if item.is_adjacent_to_number(&number_item) {
+                    if item.is_adjacent_to_number(number_item) {
                         number_item.get_number()
                     } else {
                         None
@@ -140,7 +154,7 @@ fn part2(number_items: &Vec<&SchematicItem>, symbol_items: &Vec<&SchematicItem>)
                 .collect::<Vec<usize>>();
 
             if adjacent_numbers.len() == 2 {
-                Some(adjacent_numbers.iter().fold(1, |acc, x| acc * x))
+                Some(adjacent_numbers.iter().product::<usize>())
             } else {
                 None
             }
@@ -148,43 +162,104 @@ fn part2(number_items: &Vec<&SchematicItem>, symbol_items: &Vec<&SchematicItem>)
         .sum()
 }
 
-fn main() -> Result<(), anyhow::Error> {
-    let puzzle_input = std::fs::read_to_string("inputs/day3.txt")?;
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Schematic;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let contents = puzzle_input
-        .split("\n")
-        .filter(|line| line.len() > 0)
-        .enumerate()
-        .map(|(row, line)| parse_line(line, row))
-        .collect::<Vec<Vec<SchematicItem>>>();
+    // Rows can't be skipped without moving everything below them, so lenient mode blanks each
+    // unexpected character instead, and records it as skipped
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        let lines = parser
+            .input()
+            .lines()
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>();
 
-    let number_items = contents
-        .iter()
-        .map(|row| {
-            row.iter().filter(|item| match item.content {
+        for line in &lines {
+            for (idx, c) in line.char_indices().filter(|(_, c)| !c.is_ascii_graphic()) {
+                parser.skip(
+                    parser.error(&line[idx..idx + c.len_utf8()], "a digit, '.' or a symbol"),
+                )?;
+            }
+        }
+
+        let (number_items, symbol_items) = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| parse_line(line, row))
+            .filter(|item| match item.content {
+                SchematicContent::Symbol(c) => c.is_ascii_graphic(),
                 SchematicContent::Number(_) => true,
-                _ => false,
+                SchematicContent::Empty => false,
             })
-        })
-        .flatten()
-        .collect::<Vec<&SchematicItem>>();
+            .partition(|item| matches!(item.content, SchematicContent::Number(_)));
 
-    let symbol_items = contents
-        .iter()
-        .map(|row| {
-            row.iter().filter(|item| match item.content {
-                SchematicContent::Symbol(_) => true,
-                _ => false,
-            })
+        Ok(Schematic {
+            number_items,
+            symbol_items,
         })
-        .flatten()
-        .collect::<Vec<&SchematicItem>>();
+    }
 
-    let part_sum = part1(&number_items, &symbol_items);
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(&input.number_items, &input.symbol_items)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(&input.number_items, &input.symbol_items)
+    }
+}
 
-    let gear_ratio_sum = part2(&number_items, &symbol_items);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseMode;
 
-    println!("Part sum: {}\nGear ratio sum: {}", part_sum, gear_ratio_sum);
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
 
-    Ok(())
+    #[test]
+    fn example_part1() {
+        let input = Day03::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day03::part1(&input), 4361);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day03::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day03::part2(&input), 467835);
+    }
+
+    #[test]
+    fn number_at_end_of_line() {
+        let input = Day03::parse("..*\n.12\n", ParseMode::Strict).unwrap();
+        assert_eq!(Day03::part1(&input), 12);
+    }
+
+    #[test]
+    fn lenient_blanks_unexpected_characters() {
+        let input = EXAMPLE
+            .replace("...*......", "...*...\u{7f}..")
+            .replace('#', " ");
+
+        let err = Day03::parse(&input, ParseMode::Strict).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 8));
+
+        let mut parser = Parser::new(3, &input, ParseMode::Lenient);
+        let schematic = Day03::parse_with(&mut parser).unwrap();
+        assert_eq!(parser.skipped().len(), 2);
+        assert_eq!(parser.skipped()[1].line, 4);
+        // 633 was only next to the #, and nothing is next to the blanked character
+        assert_eq!(Day03::part1(&schematic), 4361 - 633);
+    }
 }
@@ -0,0 +1,154 @@
+use rayon::prelude::*;
+
+use crate::{
+    parse::{ParseError, Parser},
+    Solution,
+};
+
+pub struct Day04;
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    id: u32,
+    winning_numbers: Vec<u32>,
+    player_numbers: Vec<u32>,
+}
+
+impl Card {
+    fn get_player_winning_numbers(&self) -> Vec<u32> {
+        self.player_numbers
+            .iter()
+            .filter(|number| self.winning_numbers.contains(number))
+            .copied()
+            .collect()
+    }
+
+    pub fn compute_points(&self) -> u32 {
+        self.get_player_winning_numbers()
+            .iter()
+            .fold(0, |acc, _| match acc {
+                0 => 1,
+                x => 2 * x,
+            })
+    }
+
+    fn get_copies_from_win(&self, scratch_cards: &[Card]) -> Vec<Card> {
+        (self.id + 1..=self.id + self.get_player_winning_numbers().len() as u32)
+            .filter_map(|id| scratch_cards.iter().find(|card| card.id == id).cloned())
+            .collect::<Vec<Card>>()
+    }
+
+    pub fn get_copies_total(&self, scratch_cards: &[Card]) -> u32 {
+        let copies = self.get_copies_from_win(scratch_cards);
+        let total = copies.iter().fold(copies.len() as u32, |acc, copy| {
+            acc + copy.get_copies_total(scratch_cards)
+        });
+
+        total
+    }
+}
+
+/// Parses whitespace separated numbers, where `value` is a slice of `line`
+fn parse_numbers(line: &str, value: &str) -> Result<Vec<u32>, ParseError> {
+    value
+        .split_whitespace()
+        .map(|s| {
+            s.parse::<u32>()
+                .map_err(|_| ParseError::at(line, s, "a number"))
+        })
+        .collect()
+}
+
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (id_str, numbers_str) = value
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(value, "Card <id>: <numbers> | <numbers>"))?;
+
+        let id_str = id_str
+            .strip_prefix("Card")
+            .ok_or_else(|| ParseError::new(value, "\"Card\""))?
+            .trim();
+        let id = id_str
+            .parse::<u32>()
+            .map_err(|_| ParseError::at(value, id_str, "a card ID"))?;
+
+        let (winning_numbers_str, player_numbers_str) = numbers_str
+            .split_once('|')
+            .ok_or_else(|| ParseError::at(value, numbers_str, "numbers split by '|'"))?;
+
+        let winning_numbers = parse_numbers(value, winning_numbers_str)?;
+        let player_numbers = parse_numbers(value, player_numbers_str)?;
+
+        Ok(Self {
+            id,
+            winning_numbers,
+            player_numbers,
+        })
+    }
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        parser.lines(parser.input(), Card::try_from)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.iter().map(|card| card.compute_points()).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.len() as u32
+            + input
+                .par_iter()
+                .map(|card| card.get_copies_total(input))
+                .sum::<u32>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseMode;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day04::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day04::part1(&input), 13);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day04::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day04::part2(&input), 30);
+    }
+
+    #[test]
+    fn padded_card_id() {
+        let card = Card::try_from("Card   7:  1  2 |  2  1").unwrap();
+        assert_eq!(card.id, 7);
+        assert_eq!(card.compute_points(), 2);
+    }
+
+    #[test]
+    fn invalid_number_location() {
+        let err = Card::try_from("Card 1: 4 5 | 6 x7").unwrap_err();
+        assert_eq!(err.column, 17);
+        assert_eq!(err.text, "x7");
+    }
+}
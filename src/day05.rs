@@ -0,0 +1,1191 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use crate::{
+    parse::{ParseError, ParseMode, Parser},
+    runner::json_string,
+    Solution,
+};
+
+pub struct Day05;
+
+/// A half-open interval of values, `start..end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl SeedRange {
+    /// The `length` values from `start`, or `None` if they would run past `u64::MAX`
+    pub fn new(start: u64, length: u64) -> Option<Self> {
+        Some(Self {
+            start,
+            end: start.checked_add(length)?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersection(&self, other: &SeedRange) -> Option<SeedRange> {
+        let range = SeedRange {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        };
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// Sorts the ranges and joins the ones that overlap or touch, dropping empty ones
+    pub fn merge(mut ranges: Vec<SeedRange>) -> Vec<SeedRange> {
+        ranges.retain(|range| !range.is_empty());
+        ranges.sort_by_key(|range| range.start);
+
+        let mut merged = Vec::<SeedRange>::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        merged
+    }
+}
+
+/// A line of a category, mapping `range_length` values from `src_start` onwards to
+/// `dest_start` onwards. Parsing checks both ranges end within `u64`, so mapping values inside
+/// them can't overflow
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlmanacEntry {
+    src_start: u64,
+    dest_start: u64,
+    range_length: u64,
+}
+
+impl AlmanacEntry {
+    pub fn dest_mapping(&self, element: u64) -> Option<u64> {
+        if self.src_range().contains(element) {
+            Some(self.dest_start + (element - self.src_start))
+        } else {
+            None
+        }
+    }
+
+    pub fn src_range(&self) -> SeedRange {
+        SeedRange {
+            start: self.src_start,
+            end: self.src_start + self.range_length,
+        }
+    }
+
+    pub fn dest_range(&self) -> SeedRange {
+        SeedRange {
+            start: self.dest_start,
+            end: self.dest_start + self.range_length,
+        }
+    }
+
+    /// The values this entry maps into `range`, the inverse of [`AlmanacEntry::dest_mapping`]
+    pub fn src_range_mapping(&self, range: SeedRange) -> Option<SeedRange> {
+        let overlap = range.intersection(&self.dest_range())?;
+
+        Some(SeedRange {
+            start: self.src_start + (overlap.start - self.dest_start),
+            end: self.src_start + (overlap.end - self.dest_start),
+        })
+    }
+
+    /// Splits `range` into the part this entry maps, moved to its destination, and the parts
+    /// before and after the entry that it leaves alone
+    pub fn dest_range_mapping(&self, range: SeedRange) -> (Option<SeedRange>, Vec<SeedRange>) {
+        let Some(overlap) = range.intersection(&self.src_range()) else {
+            return (None, vec![range]);
+        };
+
+        let mapped = SeedRange {
+            start: self.dest_start + (overlap.start - self.src_start),
+            end: self.dest_start + (overlap.end - self.src_start),
+        };
+        let unmapped = [
+            SeedRange {
+                start: range.start,
+                end: overlap.start,
+            },
+            SeedRange {
+                start: overlap.end,
+                end: range.end,
+            },
+        ]
+        .into_iter()
+        .filter(|range| !range.is_empty())
+        .collect();
+
+        (Some(mapped), unmapped)
+    }
+}
+
+impl Display for AlmanacEntry {
+    /// The entry as it is written in the almanac
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.dest_start, self.src_start, self.range_length
+        )
+    }
+}
+
+impl TryFrom<&str> for AlmanacEntry {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut iter = value.split_whitespace();
+        let mut next_number = |expected: &str| {
+            let token = iter.next().unwrap_or(&value[value.len()..]);
+            token
+                .parse::<u64>()
+                .map_err(|_| ParseError::at(value, token, expected))
+        };
+
+        let end = next_number("a destination range start")?;
+        let start = next_number("a source range start")?;
+        let length = next_number("a range length")?;
+
+        if length == 0 {
+            let token = value.split_whitespace().nth(2).unwrap_or(value);
+            return Err(ParseError::at(value, token, "a range length above 0"));
+        }
+
+        if start.max(end).checked_add(length).is_none() {
+            let token = value.split_whitespace().nth(2).unwrap_or(value);
+            return Err(ParseError::at(
+                value,
+                token,
+                format!("a range length of at most {}", u64::MAX - start.max(end)),
+            ));
+        }
+
+        if let Some(token) = iter.next() {
+            return Err(ParseError::at(value, token, "end of line"));
+        }
+
+        Ok(Self {
+            src_start: start,
+            dest_start: end,
+            range_length: length,
+        })
+    }
+}
+
+/// The entries of a "<source>-to-<destination> map:" section
+#[derive(Debug)]
+pub struct AlmanacCategory {
+    source: String,
+    destination: String,
+    /// Sorted by source start, with no two source ranges overlapping
+    entries: Vec<AlmanacEntry>,
+}
+
+impl AlmanacCategory {
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    /// Finds the entry covering the value with a binary search, since entries are sorted and
+    /// don't overlap
+    pub fn find_entry(&self, input: u64) -> Option<&AlmanacEntry> {
+        let idx = self
+            .entries
+            .partition_point(|entry| entry.src_start <= input);
+
+        idx.checked_sub(1)
+            .map(|idx| &self.entries[idx])
+            .filter(|entry| entry.src_range().contains(input))
+    }
+
+    pub fn get_dest_mapping(&self, input: &u64) -> u64 {
+        self.find_entry(*input)
+            .and_then(|entry| entry.dest_mapping(*input))
+            .unwrap_or(*input)
+    }
+
+    /// Maps a whole range at once, splitting it where it crosses entry boundaries. Values
+    /// outside every entry map to themselves
+    pub fn get_dest_ranges(&self, range: SeedRange) -> Vec<SeedRange> {
+        let mut mapped = vec![];
+        let mut unmapped = vec![range];
+
+        for entry in &self.entries {
+            let mut remaining = vec![];
+            for range in unmapped {
+                let (entry_mapped, entry_unmapped) = entry.dest_range_mapping(range);
+                mapped.extend(entry_mapped);
+                remaining.extend(entry_unmapped);
+            }
+            unmapped = remaining;
+        }
+
+        mapped.extend(unmapped);
+        mapped
+    }
+
+    /// Every source value that maps into `range`: the values entries move into it, and the
+    /// values in it that no entry covers, which map to themselves
+    pub fn get_src_ranges(&self, range: SeedRange) -> Vec<SeedRange> {
+        let mut sources = self
+            .entries
+            .iter()
+            .filter_map(|entry| entry.src_range_mapping(range))
+            .collect::<Vec<_>>();
+
+        let mut uncovered = vec![range];
+        for entry in &self.entries {
+            uncovered = uncovered
+                .into_iter()
+                .flat_map(|range| entry.dest_range_mapping(range).1)
+                .collect();
+        }
+        sources.extend(uncovered);
+
+        SeedRange::merge(sources)
+    }
+}
+
+impl AlmanacCategory {
+    /// Parses a "<source>-to-<destination> map:" header followed by one entry per line. Entries
+    /// are kept sorted by source, and one overlapping an earlier entry in the section is an
+    /// error, or skipped in lenient mode
+    fn parse(value: &str, parser: &mut Parser) -> Result<Self, ParseError> {
+        let (header, entries) = value.split_once('\n').unwrap_or((value, ""));
+
+        let (source, destination) = header
+            .trim_end()
+            .strip_suffix(" map:")
+            .and_then(|names| names.split_once("-to-"))
+            .filter(|(source, destination)| !source.is_empty() && !destination.is_empty())
+            .ok_or_else(|| parser.error(header, "a \"<source>-to-<destination> map:\" header"))?;
+
+        let mut sorted = Vec::<AlmanacEntry>::new();
+        for (line, entry) in
+            parser.lines(entries, |line| Ok((line, AlmanacEntry::try_from(line)?)))?
+        {
+            let range = entry.src_range();
+            let idx = sorted.partition_point(|other| other.src_start < entry.src_start);
+
+            // Only the neighbours either side of where it goes can overlap it
+            let overlapping = [idx.checked_sub(1), Some(idx)]
+                .into_iter()
+                .flatten()
+                .filter_map(|idx| sorted.get(idx))
+                .map(AlmanacEntry::src_range)
+                .find(|other| other.intersection(&range).is_some());
+
+            match overlapping {
+                Some(other) => {
+                    let token = line.split_whitespace().nth(1).unwrap_or(line);
+                    parser.skip(parser.error(
+                        token,
+                        format!(
+                            "a source range clear of {}..{}, not {}..{}",
+                            other.start, other.end, range.start, range.end
+                        ),
+                    ))?;
+                }
+                None => sorted.insert(idx, entry),
+            }
+        }
+
+        Ok(AlmanacCategory {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            entries: sorted,
+        })
+    }
+}
+
+/// A segment of a [`PiecewiseMap`], shifting every value up to the next segment's start
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    src_start: u64,
+    dest_start: u64,
+}
+
+/// Where every value maps to, as consecutive segments that each shift their values by a fixed
+/// amount. The first segment starts at 0 and the last one runs to `u64::MAX`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    segments: Vec<Segment>,
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        Self {
+            segments: vec![Segment {
+                src_start: 0,
+                dest_start: 0,
+            }],
+        }
+    }
+
+    /// Adds a segment after the last one, unless it just continues the last one's shift
+    fn push(&mut self, segment: Segment) {
+        if let Some(last) = self.segments.last() {
+            let continued = last
+                .dest_start
+                .checked_add(segment.src_start - last.src_start);
+            if continued == Some(segment.dest_start) {
+                return;
+            }
+        }
+
+        self.segments.push(segment);
+    }
+
+    fn segment_range(&self, idx: usize) -> SeedRange {
+        SeedRange {
+            start: self.segments[idx].src_start,
+            end: self
+                .segments
+                .get(idx + 1)
+                .map_or(u64::MAX, |segment| segment.src_start),
+        }
+    }
+
+    fn segment_index(&self, value: u64) -> usize {
+        self.segments
+            .partition_point(|segment| segment.src_start <= value)
+            - 1
+    }
+
+    pub fn get(&self, value: u64) -> u64 {
+        let segment = self.segments[self.segment_index(value)];
+        segment.dest_start + (value - segment.src_start)
+    }
+
+    /// The values where the shift changes, starting with 0
+    pub fn breakpoints(&self) -> impl Iterator<Item = u64> + '_ {
+        self.segments.iter().map(|segment| segment.src_start)
+    }
+
+    /// Maps a whole range at once, split where it crosses segments
+    pub fn get_dest_ranges(&self, range: SeedRange) -> Vec<SeedRange> {
+        if range.is_empty() {
+            return vec![];
+        }
+
+        (self.segment_index(range.start)..self.segments.len())
+            .map_while(|idx| {
+                let segment = self.segments[idx];
+                let overlap = range.intersection(&self.segment_range(idx))?;
+                Some(SeedRange {
+                    start: segment.dest_start + (overlap.start - segment.src_start),
+                    end: segment.dest_start + (overlap.end - segment.src_start),
+                })
+            })
+            .collect()
+    }
+
+    /// The value in `range` that maps lowest, and where it maps to. Within a segment that is
+    /// always the start of its overlap with the range
+    pub fn lowest(&self, range: SeedRange) -> Option<(u64, u64)> {
+        if range.is_empty() {
+            return None;
+        }
+
+        (self.segment_index(range.start)..self.segments.len())
+            .map_while(|idx| {
+                let overlap = range.intersection(&self.segment_range(idx))?;
+                Some((overlap.start, self.get(overlap.start)))
+            })
+            .min_by_key(|(_, dest)| *dest)
+    }
+
+    /// The map that applies `self` and then `next`
+    pub fn compose(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut composed = PiecewiseMap { segments: vec![] };
+
+        for (idx, segment) in self.segments.iter().enumerate() {
+            let src = self.segment_range(idx);
+            let dest = SeedRange {
+                start: segment.dest_start,
+                end: segment.dest_start.saturating_add(src.end - src.start),
+            };
+
+            // Split the segment wherever its destinations cross one of next's segments
+            for next_idx in next.segment_index(dest.start)..next.segments.len() {
+                let next_segment = next.segments[next_idx];
+                let Some(overlap) = dest.intersection(&next.segment_range(next_idx)) else {
+                    break;
+                };
+
+                composed.push(Segment {
+                    src_start: src.start + (overlap.start - dest.start),
+                    dest_start: next_segment.dest_start + (overlap.start - next_segment.src_start),
+                });
+            }
+        }
+
+        // Segment ranges end before u64::MAX, so that value is left out above and mapped on
+        // its own. It only needs a segment if it doesn't continue the last shift
+        composed.push(Segment {
+            src_start: u64::MAX,
+            dest_start: next.get(self.get(u64::MAX)),
+        });
+
+        composed
+    }
+}
+
+impl From<&AlmanacCategory> for PiecewiseMap {
+    /// Fills the gaps between entries with identity segments
+    fn from(category: &AlmanacCategory) -> Self {
+        let mut map = PiecewiseMap { segments: vec![] };
+        let mut covered = 0;
+
+        for entry in &category.entries {
+            if entry.src_start > covered {
+                map.push(Segment {
+                    src_start: covered,
+                    dest_start: covered,
+                });
+            }
+
+            map.push(Segment {
+                src_start: entry.src_start,
+                dest_start: entry.dest_start,
+            });
+            covered = entry.src_range().end;
+        }
+
+        map.push(Segment {
+            src_start: covered,
+            dest_start: covered,
+        });
+
+        map
+    }
+}
+
+impl AlmanacCategory {
+    /// Folds this category and the one after it into a single map
+    pub fn compose(&self, next: &AlmanacCategory) -> PiecewiseMap {
+        PiecewiseMap::from(self).compose(&PiecewiseMap::from(next))
+    }
+}
+
+/// The category seeds start in
+pub const SEED: &str = "seed";
+/// The category every chain of maps has to end in
+pub const LOCATION: &str = "location";
+
+#[derive(Debug)]
+pub struct Almanac {
+    seed_input: Vec<u64>,
+    seed_ranges: Vec<SeedRange>,
+    /// The categories in the order they are applied, from seed to location
+    categories: Vec<AlmanacCategory>,
+    /// Every category composed, from seed straight to location
+    seed_to_location: PiecewiseMap,
+}
+
+impl Almanac {
+    pub fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        let value = parser.input();
+        let mut iter = value.split("\n\n");
+
+        let seeds_str = iter.next().unwrap_or(value);
+        let numbers_str = seeds_str
+            .strip_prefix("seeds:")
+            .ok_or_else(|| parser.error(seeds_str, "\"seeds:\""))?;
+        let seeds = numbers_str
+            .split_whitespace()
+            .map(|seed_str| {
+                seed_str
+                    .parse::<u64>()
+                    .map(|seed| (seed_str, seed))
+                    .map_err(|_| parser.error(seed_str, "a seed number"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Part 2 reads the seeds as start and length pairs, which have to end within u64
+        let mut seed_ranges = vec![];
+        let pairs = seeds.chunks_exact(2);
+        if let [(last_seed_str, _)] = pairs.remainder() {
+            parser.skip(parser.error(last_seed_str, "a seed range length"))?;
+        }
+        for pair in pairs {
+            let ((_, start), (length_str, length)) = (pair[0], pair[1]);
+            match SeedRange::new(start, length) {
+                Some(range) => seed_ranges.push(range),
+                None => parser.skip(parser.error(
+                    length_str,
+                    format!("a seed range length of at most {}", u64::MAX - start),
+                ))?,
+            }
+        }
+
+        // Each category links its source to its destination, keyed by source along with the
+        // section it came from for errors
+        let mut links = HashMap::<String, (&str, AlmanacCategory)>::new();
+        for section in iter.filter(|section| !section.trim().is_empty()) {
+            let category = AlmanacCategory::parse(section, parser)?;
+            if links.contains_key(category.source()) {
+                return Err(parser.error(
+                    section.lines().next().unwrap_or(section),
+                    format!("a single map from {}", category.source()),
+                ));
+            }
+            links.insert(category.source.clone(), (section, category));
+        }
+
+        let mut categories = vec![];
+        let mut current = SEED.to_string();
+        while current != LOCATION {
+            let (_, category) = links.remove(&current).ok_or_else(|| {
+                parser.error(
+                    &value[value.len()..],
+                    format!("a \"{current}-to-<destination> map:\" section"),
+                )
+            })?;
+            current = category.destination.clone();
+            categories.push(category);
+        }
+
+        if let Some((section, _)) = links.values().min_by_key(|(section, _)| section.as_ptr()) {
+            return Err(parser.error(
+                section.lines().next().unwrap_or(section),
+                format!("a map on the chain from {SEED} to {LOCATION}"),
+            ));
+        }
+
+        let seed_to_location = categories
+            .iter()
+            .fold(PiecewiseMap::identity(), |map, category| {
+                map.compose(&PiecewiseMap::from(category))
+            });
+
+        Ok(Self {
+            seed_input: seeds.into_iter().map(|(_, seed)| seed).collect(),
+            seed_ranges,
+            categories,
+            seed_to_location,
+        })
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(&mut Parser::new(Day05::DAY, value, ParseMode::Strict))
+    }
+}
+
+impl Almanac {
+    /// The categories in the order they are applied, from seed to location
+    pub fn categories(&self) -> &[AlmanacCategory] {
+        &self.categories
+    }
+
+    pub fn seed_to_location(&self) -> &PiecewiseMap {
+        &self.seed_to_location
+    }
+
+    /// The categories that take values from `from` to `to`, or `None` if either isn't in the
+    /// almanac or `to` comes before `from`
+    fn chain(&self, from: &str, to: &str) -> Option<&[AlmanacCategory]> {
+        let position = |name: &str| {
+            std::iter::once(SEED)
+                .chain(self.categories.iter().map(AlmanacCategory::destination))
+                .position(|category| category == name)
+        };
+
+        let (from, to) = (position(from)?, position(to)?);
+        (from <= to).then(|| &self.categories[from..to])
+    }
+
+    /// Maps a value in category `from` forward to category `to`, like seed 79 to its humidity
+    pub fn map(&self, from: &str, to: &str, value: u64) -> Option<u64> {
+        Some(
+            self.chain(from, to)?
+                .iter()
+                .fold(value, |value, category| category.get_dest_mapping(&value)),
+        )
+    }
+
+    /// The ranges of values in category `from` that end up in `range` in category `to`, like
+    /// the seeds that reach location 35
+    pub fn sources(&self, from: &str, to: &str, range: SeedRange) -> Option<Vec<SeedRange>> {
+        Some(
+            self.chain(from, to)?
+                .iter()
+                .rev()
+                .fold(vec![range], |ranges, category| {
+                    SeedRange::merge(
+                        ranges
+                            .into_iter()
+                            .flat_map(|range| category.get_src_ranges(range))
+                            .collect(),
+                    )
+                }),
+        )
+    }
+
+    /// The seeds read as start and length pairs
+    pub fn seed_ranges(&self) -> &[SeedRange] {
+        &self.seed_ranges
+    }
+
+    pub fn get_lowest_location(&self) -> u64 {
+        self.seed_input
+            .iter()
+            .map(|seed| self.seed_to_location.get(*seed))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Maps each seed range as a whole, so the lowest location is the lowest start of the
+    /// resulting ranges
+    pub fn get_lowest_location_from_seed_range(&self) -> u64 {
+        self.seed_ranges
+            .iter()
+            .filter_map(|range| self.seed_to_location.lowest(*range))
+            .map(|(_, location)| location)
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// The seed that reaches the lowest location
+    pub fn lowest_location_seed(&self) -> Option<u64> {
+        self.seed_input
+            .iter()
+            .copied()
+            .min_by_key(|seed| self.seed_to_location.get(*seed))
+    }
+
+    /// The seed in any of the seed ranges that reaches the lowest location
+    pub fn lowest_location_seed_from_seed_range(&self) -> Option<u64> {
+        self.seed_ranges
+            .iter()
+            .filter_map(|range| self.seed_to_location.lowest(*range))
+            .min_by_key(|(_, location)| *location)
+            .map(|(seed, _)| seed)
+    }
+
+    /// Follows a seed through every category, recording the entry used at each step
+    pub fn trace(&self, seed: u64) -> Trace<'_> {
+        let mut value = seed;
+        let steps = self
+            .categories
+            .iter()
+            .map(|category| {
+                let entry = category.find_entry(value);
+                value = entry
+                    .and_then(|entry| entry.dest_mapping(value))
+                    .unwrap_or(value);
+
+                TraceStep {
+                    category: category.destination(),
+                    value,
+                    entry,
+                }
+            })
+            .collect();
+
+        Trace { seed, steps }
+    }
+}
+
+/// One step of a seed's path through the categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceStep<'a> {
+    pub category: &'a str,
+    pub value: u64,
+    /// The entry that moved the value here, or `None` if it passed through unchanged
+    pub entry: Option<&'a AlmanacEntry>,
+}
+
+/// A seed's path from seed to location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace<'a> {
+    pub seed: u64,
+    pub steps: Vec<TraceStep<'a>>,
+}
+
+impl Display for Trace<'_> {
+    /// One row per category, with the entry that matched or "identity"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .steps
+            .iter()
+            .map(|step| step.category.len())
+            .chain([SEED.len()])
+            .max()
+            .unwrap_or(0);
+
+        writeln!(f, "{SEED:<width$}  {:>20}", self.seed)?;
+        for step in &self.steps {
+            let entry = step
+                .entry
+                .map_or("identity".to_string(), AlmanacEntry::to_string);
+            writeln!(f, "{:<width$}  {:>20}  {entry}", step.category, step.value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats traces as a JSON array with one object per seed. Values are strings like the
+/// runner's answers, and an identity step has a null entry
+pub fn traces_to_json(traces: &[Trace]) -> String {
+    let objects = traces
+        .iter()
+        .map(|trace| {
+            let steps = trace
+                .steps
+                .iter()
+                .map(|step| {
+                    let entry = step
+                        .entry
+                        .map_or("null".to_string(), |entry| json_string(&entry.to_string()));
+                    format!(
+                        "{{\"category\":{},\"value\":\"{}\",\"entry\":{entry}}}",
+                        json_string(step.category),
+                        step.value
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            format!(
+                "{{\"seed\":\"{}\",\"steps\":[{}]}}",
+                trace.seed,
+                steps.join(",")
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", objects.join(","))
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Almanac;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        Almanac::parse(parser)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.get_lowest_location()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.get_lowest_location_from_seed_range()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day05::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day05::part1(&input), 35);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day05::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day05::part2(&input), 46);
+    }
+
+    #[test]
+    fn entry_range_is_half_open() {
+        let entry = AlmanacEntry::try_from("50 98 2").unwrap();
+        assert_eq!(entry.dest_mapping(97), None);
+        assert_eq!(entry.dest_mapping(98), Some(50));
+        assert_eq!(entry.dest_mapping(99), Some(51));
+        assert_eq!(entry.dest_mapping(100), None);
+    }
+
+    #[test]
+    fn ranges_split_at_entry_boundaries() {
+        let category = AlmanacCategory::parse(
+            "seed-to-soil map:\n50 98 2\n52 50 48",
+            &mut Parser::new(5, "", ParseMode::Strict),
+        )
+        .unwrap();
+
+        let mut ranges = category.get_dest_ranges(SeedRange {
+            start: 45,
+            end: 100,
+        });
+        ranges.sort_by_key(|range| range.start);
+        assert_eq!(
+            ranges,
+            vec![
+                SeedRange { start: 45, end: 50 },
+                SeedRange { start: 50, end: 52 },
+                SeedRange {
+                    start: 52,
+                    end: 100
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ranges_match_single_seeds() {
+        let almanac = Day05::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        let brute_force = Almanac {
+            seed_input: (79..93).chain(55..68).collect(),
+            seed_ranges: vec![],
+            ..Day05::parse(EXAMPLE, ParseMode::Strict).unwrap()
+        };
+
+        assert_eq!(
+            almanac.get_lowest_location_from_seed_range(),
+            brute_force.get_lowest_location()
+        );
+    }
+
+    #[test]
+    fn composed_map_matches_each_category_in_turn() {
+        let almanac = Day05::parse(EXAMPLE, ParseMode::Strict).unwrap();
+
+        for seed in 0..120 {
+            let location = almanac
+                .categories()
+                .iter()
+                .fold(seed, |value, category| category.get_dest_mapping(&value));
+            assert_eq!(
+                almanac.seed_to_location().get(seed),
+                location,
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn composed_map_keeps_u64_max() {
+        let input = "seeds: 18446744073709551615 0
+
+seed-to-location map:
+0 18446744073709551614 1
+";
+        let almanac = Day05::parse(input, ParseMode::Strict).unwrap();
+        assert_eq!(almanac.map(SEED, LOCATION, u64::MAX), Some(u64::MAX));
+        assert_eq!(almanac.seed_to_location().get(u64::MAX), u64::MAX);
+        assert_eq!(almanac.seed_to_location().get(u64::MAX - 1), 0);
+        assert_eq!(
+            almanac.seed_to_location().breakpoints().collect::<Vec<_>>(),
+            [0, u64::MAX - 1, u64::MAX]
+        );
+    }
+
+    #[test]
+    fn breakpoints_of_a_category() {
+        let almanac = Day05::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        let [seed_to_soil, soil_to_fertiliser, ..] = almanac.categories() else {
+            panic!("the example has seven categories");
+        };
+
+        let map = PiecewiseMap::from(seed_to_soil);
+        assert_eq!(map.breakpoints().collect::<Vec<_>>(), vec![0, 50, 98, 100]);
+
+        let map = seed_to_soil.compose(soil_to_fertiliser);
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(14), 53);
+    }
+
+    #[test]
+    fn map_between_categories() {
+        let almanac = Day05::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(almanac.map("seed", "soil", 79), Some(81));
+        assert_eq!(almanac.map("seed", "humidity", 79), Some(78));
+        assert_eq!(almanac.map("light", "location", 74), Some(82));
+        assert_eq!(almanac.map("soil", "soil", 5), Some(5));
+        assert_eq!(almanac.map("location", "seed", 82), None);
+        assert_eq!(almanac.map("seed", "planet", 79), None);
+    }
+
+    #[test]
+    fn sources_match_forward_mapping() {
+        let almanac = Day05::parse(EXAMPLE, ParseMode::Strict).unwrap();
+
+        let sources = almanac
+            .sources("seed", "location", SeedRange::new(35, 1).unwrap())
+            .unwrap();
+        assert!(sources
+            .iter()
+            .any(|range| (range.start..range.end).contains(&13)));
+
+        let target = SeedRange { start: 40, end: 60 };
+        let sources = almanac.sources("seed", "location", target).unwrap();
+        for seed in 0..200 {
+            let location = almanac.map("seed", "location", seed).unwrap();
+            assert_eq!(
+                sources
+                    .iter()
+                    .any(|range| (range.start..range.end).contains(&seed)),
+                (target.start..target.end).contains(&location),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn entries_are_sorted_and_searched() {
+        let category = AlmanacCategory::parse(
+            "a-to-b map:\n10 90 5\n0 20 10\n5 50 1",
+            &mut Parser::new(5, "", ParseMode::Strict),
+        )
+        .unwrap();
+
+        let starts = category
+            .entries
+            .iter()
+            .map(|entry| entry.src_start)
+            .collect::<Vec<_>>();
+        assert_eq!(starts, vec![20, 50, 90]);
+
+        for (value, mapped) in [
+            (19, 19),
+            (20, 0),
+            (29, 9),
+            (30, 30),
+            (50, 5),
+            (94, 14),
+            (95, 95),
+        ] {
+            assert_eq!(category.get_dest_mapping(&value), mapped);
+        }
+    }
+
+    #[test]
+    fn overlapping_and_empty_entries() {
+        let input = EXAMPLE.replace("52 50 48", "52 50 48\n0 60 5");
+        let err = Day05::parse(&input, ParseMode::Strict).unwrap_err();
+        assert_eq!((err.line, err.column), (6, 3));
+        assert_eq!(err.expected, "a source range clear of 50..98, not 60..65");
+
+        let mut parser = Parser::new(5, &input, ParseMode::Lenient);
+        let almanac = Day05::parse_with(&mut parser).unwrap();
+        assert_eq!(parser.skipped().len(), 1);
+        assert_eq!(Day05::part1(&almanac), 35);
+
+        let input = EXAMPLE.replace("37 52 2", "37 52 0");
+        let err = Day05::parse(&input, ParseMode::Strict).unwrap_err();
+        assert_eq!((err.line, err.column), (9, 7));
+        assert_eq!(err.expected, "a range length above 0");
+    }
+
+    #[test]
+    fn ranges_past_u64_are_rejected() {
+        let entry = AlmanacEntry::try_from("18446744073709551610 0 5").unwrap();
+        assert_eq!(entry.dest_mapping(4), Some(u64::MAX - 1));
+
+        let err = AlmanacEntry::try_from("18446744073709551610 0 6").unwrap_err();
+        assert_eq!(err.text, "6");
+        assert_eq!(err.expected, "a range length of at most 5");
+
+        let input = EXAMPLE.replace("55 13", "18446744073709551615 13");
+        let err = Day05::parse(&input, ParseMode::Strict).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 35));
+        assert_eq!(err.expected, "a seed range length of at most 0");
+
+        let almanac = Day05::parse(&input, ParseMode::Lenient).unwrap();
+        assert_eq!(almanac.seed_ranges().len(), 1);
+        assert_eq!(Day05::part2(&almanac), 46);
+    }
+
+    #[test]
+    fn values_up_to_u64_max() {
+        let input = "seeds: 1 1
+
+seed-to-soil map:
+0 18446744073709551612 3
+
+soil-to-location map:
+18446744073709551613 0 2
+5 18446744073709551614 1
+";
+        let almanac = Day05::parse(input, ParseMode::Strict).unwrap();
+
+        for value in u64::MAX - 4..=u64::MAX {
+            assert_eq!(
+                Some(almanac.seed_to_location().get(value)),
+                almanac.map(SEED, LOCATION, value),
+                "seed {value}"
+            );
+        }
+        assert_eq!(almanac.seed_to_location().get(u64::MAX - 2), u64::MAX - 1);
+        assert_eq!(almanac.seed_to_location().get(u64::MAX - 1), 2);
+        assert_eq!(almanac.seed_to_location().get(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn unpaired_seed() {
+        let input = EXAMPLE.replace("55 13", "55 13 60");
+        let err = Day05::parse(&input, ParseMode::Strict).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 20, "60"));
+        assert_eq!(err.expected, "a seed range length");
+
+        let mut parser = Parser::new(5, &input, ParseMode::Lenient);
+        let almanac = Day05::parse_with(&mut parser).unwrap();
+        assert_eq!(parser.skipped().len(), 1);
+        assert_eq!(almanac.seed_ranges().len(), 2);
+    }
+
+    #[test]
+    fn trace_of_a_seed() {
+        let almanac = Day05::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        let trace = almanac.trace(79);
+
+        let steps = trace
+            .steps
+            .iter()
+            .map(|step| {
+                let entry = step.entry.map(AlmanacEntry::to_string);
+                (step.category, step.value, entry)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            steps,
+            vec![
+                ("soil", 81, Some("52 50 48".to_string())),
+                ("fertilizer", 81, None),
+                ("water", 81, None),
+                ("light", 74, Some("18 25 70".to_string())),
+                ("temperature", 78, Some("68 64 13".to_string())),
+                ("humidity", 78, None),
+                ("location", 82, Some("60 56 37".to_string())),
+            ]
+        );
+
+        let table = trace.to_string();
+        let row = table.lines().nth(3).unwrap();
+        assert_eq!(
+            row.split_whitespace().collect::<Vec<_>>(),
+            ["water", "81", "identity"]
+        );
+        assert!(traces_to_json(&[trace]).starts_with(
+            r#"[{"seed":"79","steps":[{"category":"soil","value":"81","entry":"52 50 48"},"#
+        ));
+    }
+
+    #[test]
+    fn seeds_with_the_lowest_location() {
+        let almanac = Day05::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(almanac.lowest_location_seed(), Some(13));
+        assert_eq!(almanac.lowest_location_seed_from_seed_range(), Some(82));
+    }
+
+    #[test]
+    fn invalid_entry_location() {
+        let input = EXAMPLE.replace("37 52 2", "37 5x2 2");
+
+        let err = Day05::parse(&input, ParseMode::Strict).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (5, 9, 4));
+        assert_eq!(err.text, "5x2");
+
+        let almanac = Day05::parse(&input, ParseMode::Lenient).unwrap();
+        assert_eq!(Day05::part1(&almanac), 35);
+    }
+
+    #[test]
+    fn missing_section() {
+        let input = EXAMPLE.split("\n\nhumidity").next().unwrap();
+        let err = Day05::parse(input, ParseMode::Strict).unwrap_err();
+        assert_eq!(err.text, "");
+        assert_eq!(err.expected, "a \"humidity-to-<destination> map:\" section");
+    }
+
+    #[test]
+    fn sections_in_any_order() {
+        let (seeds, sections) = EXAMPLE.split_once("\n\n").unwrap();
+        let mut sections = sections.trim_end().split("\n\n").collect::<Vec<_>>();
+        sections.reverse();
+        let input = format!("{seeds}\n\n{}\n", sections.join("\n\n"));
+
+        let almanac = Day05::parse(&input, ParseMode::Strict).unwrap();
+        let names = almanac
+            .categories()
+            .iter()
+            .map(AlmanacCategory::destination)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "soil",
+                "fertilizer",
+                "water",
+                "light",
+                "temperature",
+                "humidity",
+                "location"
+            ]
+        );
+        assert_eq!(Day05::part1(&almanac), 35);
+    }
+
+    #[test]
+    fn duplicate_and_unused_links() {
+        let input = format!("{EXAMPLE}\nseed-to-water map:\n1 2 3\n");
+        let err = Day05::parse(&input, ParseMode::Strict).unwrap_err();
+        assert_eq!((err.line, err.column), (35, 1));
+        assert_eq!(err.expected, "a single map from seed");
+
+        let input = format!("{EXAMPLE}\nlocation-to-planet map:\n1 2 3\n");
+        let err = Day05::parse(&input, ParseMode::Strict).unwrap_err();
+        assert_eq!(err.line, 35);
+        assert_eq!(err.expected, "a map on the chain from seed to location");
+    }
+}
@@ -0,0 +1,339 @@
+use std::{num::IntErrorKind, str::FromStr};
+
+use crate::{
+    parse::{ParseError, ParseMode, Parser},
+    Solution,
+};
+
+pub struct Day06;
+
+/// Counts the hold times that beat the record. Holding for `t` travels `t * (duration - t)`,
+/// so the winners lie strictly between the roots of `t * (duration - t) = record`, and are
+/// symmetric around the middle of the race
+pub fn count_winning_combinations(duration: usize, record: usize) -> usize {
+    // Widened so the square of the duration can't overflow
+    let (duration, record) = (duration as u128, record as u128);
+    let distance = |time: u128| time * (duration - time);
+
+    let Some(discriminant) = (duration * duration).checked_sub(4 * record) else {
+        return 0;
+    };
+
+    // The integer square root puts this at most one below the first winning hold time, and
+    // stepping past anything that only ties the record finds it
+    let mut first = (duration - discriminant.isqrt()) / 2;
+    while first <= duration / 2 && distance(first) <= record {
+        first += 1;
+    }
+
+    if first > duration / 2 {
+        0
+    } else {
+        (duration - 2 * first + 1) as usize
+    }
+}
+
+/// A number in the single big race. With the `bigint` feature it has arbitrary precision, so
+/// the race can be made of any number of digits
+#[cfg(feature = "bigint")]
+pub type BigNumber = num_bigint::BigUint;
+#[cfg(not(feature = "bigint"))]
+pub type BigNumber = usize;
+
+#[cfg(not(feature = "bigint"))]
+pub fn count_big_race(duration: &BigNumber, record: &BigNumber) -> BigNumber {
+    count_winning_combinations(*duration, *record)
+}
+
+/// [`count_winning_combinations`] with arbitrary precision
+#[cfg(feature = "bigint")]
+pub fn count_big_race(duration: &BigNumber, record: &BigNumber) -> BigNumber {
+    use num_traits::Zero;
+
+    let distance = |time: &BigNumber| time * (duration - time);
+    let half = duration / 2u32;
+
+    let square = duration * duration;
+    let four_records = record * 4u32;
+    if square < four_records {
+        return BigNumber::zero();
+    }
+
+    let mut first = (duration - (square - four_records).sqrt()) / 2u32;
+    while first <= half && distance(&first) <= *record {
+        first += 1u32;
+    }
+
+    if first > half {
+        BigNumber::zero()
+    } else {
+        duration - first * 2u32 + 1u32
+    }
+}
+
+/// The "Time:" and "Distance:" lines of a race sheet, read either as separate races, one per
+/// column, or as a single kerned race with the spaces between the columns removed
+#[derive(Debug)]
+pub struct RaceSheet {
+    races: Vec<(usize, usize)>,
+    kerned_race: (BigNumber, BigNumber),
+}
+
+/// The line starting with `label` and the numbers after it
+fn numbers_after<'a>(
+    parser: &Parser<'a>,
+    line: Option<&'a str>,
+    label: &str,
+) -> Result<(&'a str, Vec<&'a str>), ParseError> {
+    let input = parser.input();
+    let line =
+        line.ok_or_else(|| parser.error(&input[input.len()..], format!("a \"{label}\" line")))?;
+
+    let numbers = line
+        .strip_prefix(label)
+        .ok_or_else(|| parser.error(line, format!("\"{label}\"")))?;
+
+    Ok((line, numbers.split_whitespace().collect()))
+}
+
+/// Reads the columns as one number, which is where a long sheet can outgrow usize
+fn kerned(parser: &Parser, columns: &[&str]) -> Result<BigNumber, ParseError> {
+    columns.concat().parse::<BigNumber>().map_err(|_| {
+        parser.error(
+            columns[0],
+            "a race that fits in usize, or the bigint feature",
+        )
+    })
+}
+
+impl RaceSheet {
+    pub fn parse(parser: &Parser) -> Result<Self, ParseError> {
+        let input = parser.input();
+        let mut lines = input.lines().filter(|line| !line.trim().is_empty());
+
+        let (time_line, times) = numbers_after(parser, lines.next(), "Time:")?;
+        let (record_line, records) = numbers_after(parser, lines.next(), "Distance:")?;
+
+        if let Some(line) = lines.next() {
+            return Err(parser.error(line, "end of input"));
+        }
+        if times.is_empty() {
+            return Err(parser.error(&time_line[time_line.len()..], "a race time"));
+        }
+        if records.len() != times.len() {
+            let token = records
+                .get(times.len())
+                .copied()
+                .unwrap_or(&record_line[record_line.len()..]);
+            return Err(parser.error(
+                token,
+                format!("one distance for each of the {} times", times.len()),
+            ));
+        }
+
+        let parse_number = |token: &str| {
+            token.parse::<usize>().map_err(|err| {
+                // Each column is a race of its own, so the bigint feature doesn't help here
+                let expected = match err.kind() {
+                    IntErrorKind::PosOverflow => "a race that fits in usize",
+                    _ => "a number",
+                };
+                parser.error(token, expected)
+            })
+        };
+        let races = times
+            .iter()
+            .zip(&records)
+            .map(|(time, record)| Ok((parse_number(time)?, parse_number(record)?)))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        Ok(Self {
+            races,
+            kerned_race: (kerned(parser, &times)?, kerned(parser, &records)?),
+        })
+    }
+
+    /// Each column as its own race of duration and record
+    pub fn races(&self) -> &[(usize, usize)] {
+        &self.races
+    }
+
+    /// The duration and record of the columns read as one race
+    pub fn kerned_race(&self) -> (&BigNumber, &BigNumber) {
+        (&self.kerned_race.0, &self.kerned_race.1)
+    }
+}
+
+impl FromStr for RaceSheet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(&Parser::new(Day06::DAY, s, ParseMode::Strict))
+    }
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = RaceSheet;
+    type Part1 = usize;
+    type Part2 = BigNumber;
+
+    // Both lines are needed for any race, so there are no lines to leave out in lenient mode
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        RaceSheet::parse(parser)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input
+            .races()
+            .iter()
+            .map(|(duration, record)| count_winning_combinations(*duration, *record))
+            .product()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let (duration, record) = input.kerned_race();
+
+        count_big_race(duration, record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day06::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day06::part1(&input), 288);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day06::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day06::part2(&input).to_string(), "71503");
+    }
+
+    #[test]
+    fn ties_with_record_dont_win() {
+        // Holding for 10 or 20 in a 30 long race exactly matches the record of 200
+        assert_eq!(count_winning_combinations(30, 200), 9);
+        assert_eq!(count_winning_combinations(4, 4), 0);
+    }
+
+    #[test]
+    fn closed_form_matches_brute_force() {
+        for duration in 0..80 {
+            for record in 0..duration * duration / 4 + 3 {
+                let brute_force = (1..duration)
+                    .filter(|time| time * (duration - time) > record)
+                    .count();
+                assert_eq!(
+                    count_winning_combinations(duration, record),
+                    brute_force,
+                    "duration {duration}, record {record}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn races_past_usize() {
+        // Splits the numbers into columns that each fit in usize, like a long races file
+        let sheet = |duration: &str, record: &str| {
+            let columns = |number: &str| {
+                number
+                    .as_bytes()
+                    .chunks(10)
+                    .map(|chunk| std::str::from_utf8(chunk).unwrap())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            // Padded so both lines have the same number of columns
+            let width = duration.len().max(record.len());
+            format!(
+                "Time: {}\nDistance: {}\n",
+                columns(&format!("{duration:0>width$}")),
+                columns(&format!("{record:0>width$}"))
+            )
+        };
+
+        let duration = BigNumber::from(10u32).pow(40);
+        let half = &duration / 2u32;
+
+        let races = Day06::parse(&sheet(&duration.to_string(), "0"), ParseMode::Strict).unwrap();
+        assert_eq!(Day06::part2(&races).to_string(), "9".repeat(40));
+
+        // Only holding for exactly half the race beats a record one short of the best distance
+        let record = &half * &half - 1u32;
+        let input = sheet(&duration.to_string(), &record.to_string());
+        let races = Day06::parse(&input, ParseMode::Strict).unwrap();
+        assert_eq!(Day06::part2(&races).to_string(), "1");
+
+        let record = &half * &half;
+        let input = sheet(&duration.to_string(), &record.to_string());
+        let races = Day06::parse(&input, ParseMode::Strict).unwrap();
+        assert_eq!(Day06::part2(&races).to_string(), "0");
+    }
+
+    #[test]
+    fn sheet_views() {
+        let sheet = EXAMPLE.parse::<RaceSheet>().unwrap();
+        assert_eq!(sheet.races(), [(7, 9), (15, 40), (30, 200)]);
+        let (duration, record) = sheet.kerned_race();
+        assert_eq!(
+            (duration.to_string(), record.to_string()),
+            ("71530".into(), "940200".into())
+        );
+    }
+
+    #[test]
+    fn mismatched_columns() {
+        let err = "Time: 7 15 30\nDistance: 9 40\n"
+            .parse::<RaceSheet>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 15));
+        assert_eq!(err.expected, "one distance for each of the 3 times");
+
+        let err = "Time: 7 15\nDistance: 9 40 200\n"
+            .parse::<RaceSheet>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 16));
+        assert_eq!(err.text, "200");
+    }
+
+    #[test]
+    fn malformed_sheets() {
+        let err = "Time: 7 x\nDistance: 9 40\n"
+            .parse::<RaceSheet>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+        assert_eq!(err.expected, "a number");
+
+        let err = "Time: 7 99999999999999999999\nDistance: 9 40\n"
+            .parse::<RaceSheet>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+        assert_eq!(err.expected, "a race that fits in usize");
+
+        let err = "Time:\nDistance:\n".parse::<RaceSheet>().unwrap_err();
+        assert_eq!(err.expected, "a race time");
+
+        let err = "Time: 7\nDistance: 9\nTime: 8\n"
+            .parse::<RaceSheet>()
+            .unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (3, "end of input"));
+    }
+
+    #[test]
+    fn missing_distance_line() {
+        let err = Day06::parse("Time: 7 15\n", ParseMode::Strict).unwrap_err();
+        assert_eq!((err.day, err.line), (6, 2));
+        assert_eq!(err.expected, "a \"Distance:\" line");
+    }
+}
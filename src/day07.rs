@@ -0,0 +1,796 @@
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    parse::{ParseError, Parser},
+    Solution,
+};
+
+pub struct Day07;
+
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum Type {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl Type {
+    /// Every type, weakest first
+    pub const ALL: [Type; 7] = [
+        Type::HighCard,
+        Type::OnePair,
+        Type::TwoPair,
+        Type::ThreeOfAKind,
+        Type::FullHouse,
+        Type::FourOfAKind,
+        Type::FiveOfAKind,
+    ];
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Type::HighCard => "high card",
+            Type::OnePair => "one pair",
+            Type::TwoPair => "two pair",
+            Type::ThreeOfAKind => "three of a kind",
+            Type::FullHouse => "full house",
+            Type::FourOfAKind => "four of a kind",
+            Type::FiveOfAKind => "five of a kind",
+        };
+        // Padding applies to the name, so types line up in tables
+        f.pad(name)
+    }
+}
+
+impl From<[Card; 5]> for Type {
+    /// Classifies five cards, where wild cards join whichever group of the other cards makes
+    /// the strongest type
+    fn from(cards: [Card; 5]) -> Self {
+        let mut strengths = [0; 5];
+        let mut tame = 0;
+        for card in cards.iter().filter(|card| !card.wild) {
+            strengths[tame] = card.strength;
+            tame += 1;
+        }
+        let strengths = &mut strengths[..tame];
+        strengths.sort_unstable();
+
+        // Sizes of the groups of equal cards, largest first and padded with empty groups
+        let mut groups = [0; 5];
+        for (group, equal) in groups.iter_mut().zip(strengths.chunk_by(|a, b| a == b)) {
+            *group = equal.len();
+        }
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+
+        // Adding every wild card to the largest group of the others always makes the best type
+        groups[0] += cards.len() - tame;
+
+        match groups {
+            [5, ..] => Type::FiveOfAKind,
+            [4, ..] => Type::FourOfAKind,
+            [3, 2, ..] => Type::FullHouse,
+            [3, ..] => Type::ThreeOfAKind,
+            [2, 2, ..] => Type::TwoPair,
+            [2, ..] => Type::OnePair,
+            _ => Type::HighCard,
+        }
+    }
+}
+
+/// Which labels are in the deck, how they rank and which of them are wild
+///
+/// Besides the [`jacks`](RuleSet::jacks) and [`jokers`](RuleSet::jokers) of the puzzle, a rule
+/// set can be read from its labels from weakest to strongest, optionally followed by a slash and
+/// the wild labels, so `23456789TJQKA/2` plays deuces wild.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    /// Weakest first
+    labels: Vec<char>,
+    wild: Vec<char>,
+}
+
+impl RuleSet {
+    /// Ranks `labels`, given weakest first, with the `wild` ones standing in for any other card
+    pub fn new(labels: &str, wild: &str) -> Result<Self, anyhow::Error> {
+        let labels = labels.chars().collect::<Vec<_>>();
+        let wild = wild.chars().collect::<Vec<_>>();
+
+        if labels.is_empty() {
+            anyhow::bail!("A ranking needs at least one label");
+        }
+        // Strengths are packed into a byte of the sort key of a hand
+        if labels.len() > u8::MAX as usize {
+            anyhow::bail!("A ranking can't have more than {} labels", u8::MAX);
+        }
+        // Commas and quotes would need escaping in the report's CSV
+        if let Some(label) = labels
+            .iter()
+            .find(|&&label| label.is_whitespace() || matches!(label, '/' | ',' | '"'))
+        {
+            anyhow::bail!("{label:?} can't be a card label");
+        }
+        if let Some(label) = labels.iter().duplicates().next() {
+            anyhow::bail!("{label} is ranked more than once");
+        }
+        if let Some(label) = wild.iter().find(|label| !labels.contains(label)) {
+            anyhow::bail!("Wild label {label} isn't ranked");
+        }
+
+        Ok(Self { labels, wild })
+    }
+
+    /// J is a jack, ranked between T and Q, as in part 1
+    pub fn jacks() -> Self {
+        Self::new("23456789TJQKA", "").expect("Valid ranking")
+    }
+
+    /// J is a joker, the weakest card but able to stand in for any other, as in part 2
+    pub fn jokers() -> Self {
+        Self::new("J23456789TQKA", "J").expect("Valid ranking")
+    }
+
+    /// The card for `label` under these rules, if it is in the deck
+    pub fn card(&self, label: char) -> Option<Card> {
+        let position = self.labels.iter().position(|&ranked| ranked == label)?;
+
+        Some(Card {
+            label,
+            strength: position as u8 + 1,
+            wild: self.wild.contains(&label),
+        })
+    }
+}
+
+/// Reads back with [`FromStr`]
+impl Display for RuleSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.labels.iter().collect::<String>())?;
+        if !self.wild.is_empty() {
+            write!(f, "/{}", self.wild.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::jacks()
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jacks" => Ok(Self::jacks()),
+            "jokers" => Ok(Self::jokers()),
+            _ => {
+                let (labels, wild) = s.split_once('/').unwrap_or((s, ""));
+                Self::new(labels, wild)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Eq, Copy, Clone)]
+pub struct Card {
+    label: char,
+    /// Position in the ranking, from 1 for the weakest label
+    strength: u8,
+    /// Stands in for any other card when classifying the hand
+    wild: bool,
+}
+
+// Agrees with Ord, so cards with the same strength from different rule sets aren't equal
+impl PartialEq for Card {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl Hash for Card {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.strength, self.label, self.wild).hash(state);
+    }
+}
+
+/// Cards rank by strength. Within one rule set no two labels share a strength, and the label
+/// and wildness only break ties between cards of different rule sets
+impl Ord for Card {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.strength, self.label, self.wild).cmp(&(other.strength, other.label, other.wild))
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A line of the puzzle input, five card labels and the bid on them, before a [`RuleSet`]
+/// gives the cards their strength
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deal {
+    labels: [char; 5],
+    pub bid_amount: u32,
+}
+
+impl Deal {
+    /// Parses a line of the puzzle input, with labels from the deck of `rules`
+    pub fn parse(s: &str, rules: &RuleSet) -> Result<Self, ParseError> {
+        let mut iter = s.split_whitespace();
+        let cards_str = iter.next().unwrap_or(s);
+        let mut labels = ['\0'; 5];
+        let mut count = 0;
+        for (idx, c) in cards_str.char_indices() {
+            if rules.card(c).is_none() {
+                let token = &cards_str[idx..idx + c.len_utf8()];
+                return Err(ParseError::at(s, token, "a card label"));
+            }
+            if let Some(label) = labels.get_mut(count) {
+                *label = c;
+            }
+            count += 1;
+        }
+
+        if count != labels.len() {
+            return Err(ParseError::at(s, cards_str, "five cards"));
+        }
+
+        let bid_str = iter.next().unwrap_or(&s[s.len()..]);
+        let bid_amount = bid_str
+            .parse::<u32>()
+            .map_err(|_| ParseError::at(s, bid_str, "a bid amount"))?;
+
+        if let Some(token) = iter.next() {
+            return Err(ParseError::at(s, token, "end of line"));
+        }
+
+        Ok(Self { labels, bid_amount })
+    }
+
+    /// The hand these cards make under `rules`, if they are all in its deck
+    pub fn hand(&self, rules: &RuleSet) -> Option<Hand> {
+        let mut cards = [rules.card(self.labels[0])?; 5];
+        for (card, &label) in cards.iter_mut().zip(&self.labels).skip(1) {
+            *card = rules.card(label)?;
+        }
+
+        Some(Hand::new(cards, self.bid_amount))
+    }
+}
+
+// The puzzle's deck, which both of its rule sets share
+impl FromStr for Deal {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, &RuleSet::default())
+    }
+}
+
+#[derive(Debug, Clone, Eq)]
+pub struct Hand {
+    cards: [Card; 5],
+    pub bid_amount: u32,
+    pub hand_type: Type,
+    /// The type and the strength of each card packed into one integer, see [`Hand::key`]
+    key: u64,
+}
+
+impl Hand {
+    pub fn new(cards: [Card; 5], bid_amount: u32) -> Self {
+        let hand_type = Type::from(cards);
+        let key = cards.iter().fold(hand_type as u64, |key, card| {
+            key << 8 | u64::from(card.strength)
+        });
+
+        Self {
+            cards,
+            bid_amount,
+            hand_type,
+            key,
+        }
+    }
+
+    pub fn cards(&self) -> &[Card; 5] {
+        &self.cards
+    }
+
+    /// The hand with every wild card replaced by the card it stands in for, which is the most
+    /// common other card, or the strongest card of `rules` if they are all wild. `None` if
+    /// there are no wild cards to replace
+    pub fn promoted(&self, rules: &RuleSet) -> Option<Hand> {
+        if !self.cards.iter().any(|card| card.wild) {
+            return None;
+        }
+
+        let stand_in = self
+            .cards
+            .iter()
+            .filter(|card| !card.wild)
+            .max_by_key(|card| {
+                (
+                    self.cards.iter().filter(|other| other == card).count(),
+                    **card,
+                )
+            })
+            .copied()
+            .or_else(|| {
+                rules
+                    .labels
+                    .iter()
+                    .rev()
+                    .find(|label| !rules.wild.contains(label))
+                    .and_then(|&label| rules.card(label))
+            })?;
+
+        let cards = self
+            .cards
+            .map(|card| if card.wild { stand_in } else { card });
+        Some(Hand::new(cards, self.bid_amount))
+    }
+
+    /// The type above the strengths of the cards in the order they were dealt, a byte each,
+    /// so comparing keys compares hands of the same rule set by everything but their bids
+    pub fn key(&self) -> u64 {
+        self.key
+    }
+
+    /// Parses a line of the puzzle input, with the cards played by `rules`
+    pub fn parse(s: &str, rules: &RuleSet) -> Result<Self, ParseError> {
+        Ok(Deal::parse(s, rules)?
+            .hand(rules)
+            .expect("Labels are checked against the rules"))
+    }
+}
+
+// Agrees with Ord, so hands are only equal if their bids are too
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+/// Hands rank by type, then by their cards in the order they were dealt. Hands with the same
+/// cards are ordered by bid, the smaller bid ranking lower, so the winnings don't depend on
+/// the order the hands were given in
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key
+            .cmp(&other.key)
+            // Only hands of different rule sets can share a key but not their cards
+            .then_with(|| self.cards.cmp(&other.cards))
+            .then_with(|| self.bid_amount.cmp(&other.bid_amount))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The labels of the cards, without the bid
+impl Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.cards.iter().try_for_each(|card| write!(f, "{card}"))
+    }
+}
+
+/// Sum of each hand's bid multiplied by its rank, where the weakest hand has rank 1
+///
+/// The hands are expected to share a rule set, so ranking them by key and bid agrees with
+/// their [`Ord`] while only comparing integers
+pub fn total_winnings(hands: &[Hand]) -> usize {
+    let mut ranked = hands
+        .iter()
+        .map(|hand| (hand.key, hand.bid_amount))
+        .collect::<Vec<_>>();
+    ranked.sort_unstable();
+
+    ranked
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (idx, (_, bid_amount))| {
+            acc + (idx + 1) * *bid_amount as usize
+        })
+}
+
+/// A hand's place in the ranking and what it adds to the winnings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedHand<'a> {
+    /// 1 for the weakest hand
+    pub rank: usize,
+    pub hand: &'a Hand,
+    pub winnings: usize,
+    /// The hand with its wild cards replaced, if it has any
+    pub promoted: Option<Hand>,
+}
+
+/// How a set of hands played by one rule set ranks, hand by hand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<'a> {
+    pub rules: &'a RuleSet,
+    /// The number of hands of each type, weakest type first
+    pub types: [(Type, usize); 7],
+    /// Weakest hand first
+    pub hands: Vec<RankedHand<'a>>,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(hands: &'a [Hand], rules: &'a RuleSet) -> Self {
+        let mut types = Type::ALL.map(|hand_type| (hand_type, 0));
+        for hand in hands {
+            types[hand.hand_type as usize].1 += 1;
+        }
+
+        let mut ranked = hands.iter().collect::<Vec<_>>();
+        ranked.sort_unstable();
+        let hands = ranked
+            .into_iter()
+            .enumerate()
+            .map(|(idx, hand)| RankedHand {
+                rank: idx + 1,
+                hand,
+                winnings: (idx + 1) * hand.bid_amount as usize,
+                promoted: hand.promoted(rules),
+            })
+            .collect();
+
+        Self {
+            rules,
+            types,
+            hands,
+        }
+    }
+
+    pub fn total_winnings(&self) -> usize {
+        self.hands.iter().map(|ranked| ranked.winnings).sum()
+    }
+
+    /// One line per hand, weakest first, under a header naming the columns. The promoted hand
+    /// is empty for hands without wild cards
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("rules,rank,hand,type,bid,winnings,promoted\n");
+        for ranked in &self.hands {
+            let promoted = ranked
+                .promoted
+                .as_ref()
+                .map_or(String::new(), Hand::to_string);
+            csv += &format!(
+                "{},{},{},{},{},{},{promoted}\n",
+                self.rules,
+                ranked.rank,
+                ranked.hand,
+                ranked.hand.hand_type,
+                ranked.hand.bid_amount,
+                ranked.winnings
+            );
+        }
+        csv
+    }
+}
+
+impl Display for Report<'_> {
+    /// The number of hands of each type, then one row per hand and the total winnings
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Rules {}", self.rules)?;
+        writeln!(f)?;
+        writeln!(f, "{:<15}  {:>6}", "Type", "Hands")?;
+        for (hand_type, count) in &self.types {
+            writeln!(f, "{hand_type:<15}  {count:>6}")?;
+        }
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:>6}  {:<5}  {:<15}  {:>6}  {:>12}  Promoted",
+            "Rank", "Hand", "Type", "Bid", "Winnings"
+        )?;
+        for ranked in &self.hands {
+            let promoted = ranked
+                .promoted
+                .as_ref()
+                .map_or("-".to_string(), Hand::to_string);
+            writeln!(
+                f,
+                "{:>6}  {:<5}  {:<15}  {:>6}  {:>12}  {promoted}",
+                ranked.rank,
+                ranked.hand,
+                ranked.hand.hand_type,
+                ranked.hand.bid_amount,
+                ranked.winnings
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Total winnings {}", self.total_winnings())
+    }
+}
+
+/// The hands of every deal played by `rules`, if they are all in its deck
+pub fn hands(deals: &[Deal], rules: &RuleSet) -> Option<Vec<Hand>> {
+    deals.iter().map(|deal| deal.hand(rules)).collect()
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    // Deals rather than hands, so both parts can play them by their own rules
+    type Input = Vec<Deal>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        parser.lines(parser.input(), Deal::from_str)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        total_winnings(&hands(input, &RuleSet::jacks()).expect("Deals are in the puzzle's deck"))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        total_winnings(&hands(input, &RuleSet::jokers()).expect("Deals are in the puzzle's deck"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseMode;
+
+    const EXAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    fn hand_type(hand: &str, rules: &RuleSet) -> Type {
+        Hand::parse(&format!("{hand} 1"), rules).unwrap().hand_type
+    }
+
+    #[test]
+    fn example_part1() {
+        let input = Day07::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day07::part1(&input), 6440);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day07::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day07::part2(&input), 5905);
+    }
+
+    #[test]
+    fn all_jokers() {
+        assert_eq!(hand_type("JJJJJ", &RuleSet::jokers()), Type::FiveOfAKind);
+        assert_eq!(hand_type("JJJJ2", &RuleSet::jokers()), Type::FiveOfAKind);
+    }
+
+    #[test]
+    fn jokers_join_the_best_group() {
+        assert_eq!(hand_type("J2345", &RuleSet::jokers()), Type::OnePair);
+        assert_eq!(hand_type("2J223", &RuleSet::jokers()), Type::FourOfAKind);
+        assert_eq!(hand_type("22J33", &RuleSet::jokers()), Type::FullHouse);
+        assert_eq!(hand_type("KTJJT", &RuleSet::jokers()), Type::FourOfAKind);
+    }
+
+    #[test]
+    fn invalid_card_location() {
+        let err = Deal::from_str("32X3K 765").unwrap_err();
+        assert_eq!(err.column, 3);
+        assert_eq!(err.text, "X");
+
+        let err = Deal::from_str("32T3 765").unwrap_err();
+        assert_eq!(err.expected, "five cards");
+
+        let err = Deal::from_str("32T3K 765 999").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (11, "999"));
+        assert_eq!(err.expected, "end of line");
+    }
+
+    #[test]
+    fn jokers_are_the_weakest_card() {
+        let joker_hand = Hand::parse("JKKK2 1", &RuleSet::jokers()).unwrap();
+        let queen_hand = Hand::parse("QQQQ2 1", &RuleSet::jokers()).unwrap();
+        assert_eq!(joker_hand.hand_type, queen_hand.hand_type);
+        assert!(joker_hand < queen_hand);
+    }
+
+    #[test]
+    fn jacks_are_not_wild() {
+        assert_eq!(hand_type("KTJJT", &RuleSet::jacks()), Type::TwoPair);
+        assert_eq!(hand_type("JJJJ2", &RuleSet::jacks()), Type::FourOfAKind);
+
+        let jack_hand = Hand::parse("JKKK2 1", &RuleSet::jacks()).unwrap();
+        let queen_hand = Hand::parse("QKKK2 1", &RuleSet::jacks()).unwrap();
+        assert!(jack_hand < queen_hand);
+    }
+
+    #[test]
+    fn any_number_of_wild_cards() {
+        let rules = "J23456789TQKA/JQ".parse::<RuleSet>().unwrap();
+        assert_eq!(hand_type("JJQQQ", &rules), Type::FiveOfAKind);
+        assert_eq!(hand_type("JQ234", &rules), Type::ThreeOfAKind);
+        assert_eq!(hand_type("JQ233", &rules), Type::FourOfAKind);
+        assert_eq!(hand_type("Q2233", &rules), Type::FullHouse);
+    }
+
+    #[test]
+    fn deuces_wild() {
+        let rules = "23456789TJQKA/2".parse::<RuleSet>().unwrap();
+        assert_eq!(hand_type("2KQJT", &rules), Type::OnePair);
+        assert_eq!(hand_type("22AAK", &rules), Type::FourOfAKind);
+
+        // Deuces keep their place as the weakest card when breaking ties
+        let deuce_first = Hand::parse("2AAAA 1", &rules).unwrap();
+        let deuce_last = Hand::parse("AAAA2 1", &rules).unwrap();
+        assert_eq!(deuce_first.hand_type, Type::FiveOfAKind);
+        assert!(deuce_first < deuce_last);
+    }
+
+    #[test]
+    fn custom_deck() {
+        let rules = "123ABC".parse::<RuleSet>().unwrap();
+        assert!(Hand::parse("AB123 1", &rules).unwrap() > Hand::parse("1AB23 1", &rules).unwrap());
+
+        let err = Deal::parse("AB12K 1", &rules).unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (5, "K"));
+
+        let deal = Deal::parse("AB123 1", &rules).unwrap();
+        assert!(deal.hand(&RuleSet::jacks()).is_none());
+    }
+
+    #[test]
+    fn invalid_rule_sets() {
+        assert!("".parse::<RuleSet>().is_err());
+        assert!("23456789TJQKA2".parse::<RuleSet>().is_err());
+        assert!("23456789TQKA/J".parse::<RuleSet>().is_err());
+        assert!("23 45".parse::<RuleSet>().is_err());
+        assert!("2,3456789TJQKA".parse::<RuleSet>().is_err());
+        assert!("2\"3456789TJQKA".parse::<RuleSet>().is_err());
+    }
+
+    /// Every hand of up to two labels with one of two bids, played by both rule sets
+    fn sample_hands() -> Vec<Hand> {
+        let mut hands = vec![];
+        for rules in [RuleSet::jacks(), RuleSet::jokers()] {
+            for cards in std::iter::repeat_n(['J', 'T'], 5).multi_cartesian_product() {
+                for bid in [1, 2] {
+                    let line = format!("{} {bid}", cards.iter().collect::<String>());
+                    hands.push(Hand::parse(&line, &rules).unwrap());
+                }
+            }
+        }
+        hands
+    }
+
+    #[test]
+    fn hand_order_is_total() {
+        let hands = sample_hands();
+        for first in &hands {
+            assert_eq!(first.cmp(first), std::cmp::Ordering::Equal);
+            assert_eq!(first, first);
+
+            for second in &hands {
+                assert_eq!(first.cmp(second), second.cmp(first).reverse());
+                assert_eq!(first == second, first.cmp(second).is_eq());
+
+                for third in &hands {
+                    if first <= second && second <= third {
+                        assert!(first <= third, "{first:?} {second:?} {third:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ties_are_broken_by_bid() {
+        let low = Hand::parse("KK677 28", &RuleSet::jacks()).unwrap();
+        let high = Hand::parse("KK677 30", &RuleSet::jacks()).unwrap();
+        assert!(low < high);
+        assert_ne!(low, high);
+
+        let forwards = Day07::parse("KK677 28\nKK677 30\n", ParseMode::Strict).unwrap();
+        let backwards = Day07::parse("KK677 30\nKK677 28\n", ParseMode::Strict).unwrap();
+        assert_eq!(Day07::part1(&forwards), 88);
+        assert_eq!(Day07::part1(&backwards), 88);
+    }
+
+    #[test]
+    fn keys_rank_like_hands() {
+        let mut hands = std::iter::repeat_n("23JQA".chars(), 5)
+            .multi_cartesian_product()
+            .enumerate()
+            .map(|(idx, labels)| {
+                let line = format!("{} {}", labels.iter().collect::<String>(), idx % 7);
+                Hand::parse(&line, &RuleSet::jokers()).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let mut by_key = hands.clone();
+
+        hands.sort();
+        by_key.sort_unstable_by_key(|hand| (hand.key(), hand.bid_amount));
+        assert_eq!(hands, by_key);
+    }
+
+    #[test]
+    fn promoted_hands() {
+        let rules = RuleSet::jokers();
+        let promoted = |hand: &str| {
+            Hand::parse(&format!("{hand} 1"), &rules)
+                .unwrap()
+                .promoted(&rules)
+                .map(|hand| hand.to_string())
+        };
+
+        assert_eq!(promoted("KTJJT").as_deref(), Some("KTTTT"));
+        assert_eq!(promoted("J2345").as_deref(), Some("52345"));
+        assert_eq!(promoted("JJJJJ").as_deref(), Some("AAAAA"));
+        assert_eq!(promoted("32T3K"), None);
+    }
+
+    #[test]
+    fn example_report() {
+        let rules = RuleSet::jokers();
+        let hands = EXAMPLE
+            .lines()
+            .map(|line| Hand::parse(line, &rules).unwrap())
+            .collect::<Vec<_>>();
+        let report = Report::new(&hands, &rules);
+
+        assert_eq!(report.total_winnings(), 5905);
+        assert_eq!(
+            report.types[Type::FourOfAKind as usize],
+            (Type::FourOfAKind, 3)
+        );
+        assert_eq!(report.types[Type::OnePair as usize], (Type::OnePair, 1));
+
+        let strongest = report.hands.last().unwrap();
+        assert_eq!((strongest.rank, strongest.winnings), (5, 1100));
+        assert_eq!(strongest.hand.to_string(), "KTJJT");
+        assert_eq!(
+            strongest.promoted.as_ref().unwrap().hand_type,
+            Type::FourOfAKind
+        );
+
+        let csv = report.to_csv();
+        assert_eq!(csv.lines().count(), 6);
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("J23456789TQKA/J,1,32T3K,one pair,765,765,")
+        );
+        assert!(csv.ends_with(",5,KTJJT,four of a kind,220,1100,KTTTT\n"));
+    }
+
+    #[test]
+    fn rule_sets_read_back() {
+        for rules in ["jacks", "jokers", "23456789TJQKA/2"] {
+            let rules = rules.parse::<RuleSet>().unwrap();
+            assert_eq!(rules.to_string().parse::<RuleSet>().unwrap(), rules);
+        }
+        assert_eq!(RuleSet::jokers().to_string(), "J23456789TQKA/J");
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    parse::{ParseError, Parser},
+    Solution, Unsolved,
+};
+
+pub struct Day08;
+
+fn lcm(first: usize, second: usize) -> usize {
+    (first * second) / gcd(first, second)
+}
+
+fn gcd(mut n: usize, mut m: usize) -> usize {
+    assert!(n != 0 && m != 0);
+    while m != 0 {
+        if m < n {
+            std::mem::swap(&mut m, &mut n);
+        }
+        m %= n;
+    }
+    n
+}
+
+/// Left/right instructions (0 is left, 1 is right) and the node graph they are followed through
+#[derive(Debug)]
+pub struct Network {
+    instructions: Vec<u32>,
+    graph: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    /// Walks every node ending in A simultaneously until they all stand on a node ending in Z,
+    /// by finding the cycle length of each start and taking the LCM of those
+    pub fn ghost_steps(&self) -> usize {
+        let instructions = &self.instructions;
+        let graph = &self.graph;
+
+        let mut steps = 0;
+
+        let mut starts = graph
+            .iter()
+            .filter(|(k, _)| k.ends_with('A'))
+            .collect::<Vec<_>>();
+
+        let num_instructions = instructions.len();
+        let mut count = 0;
+        let mut all_at_end = false;
+
+        let mut steps_map = HashMap::<String, usize>::new();
+
+        let num_start = starts.len();
+        let mut finished = 0;
+
+        while !all_at_end {
+            if count == num_instructions {
+                count = 0;
+            }
+
+            steps += 1;
+
+            let instruction = instructions[count];
+
+            for start in starts.iter_mut() {
+                if (*start.0).ends_with('Z') && !steps_map.contains_key(start.0) {
+                    steps_map.insert((*start.0).clone(), steps - 1);
+                    finished += 1;
+                } else if (*start.0).ends_with('Z') {
+                    continue;
+                } else {
+                    let (_, (left, right)) = *start;
+
+                    if instruction == 0 {
+                        *start = graph.get_key_value(left).expect("Should exist");
+                    } else {
+                        *start = graph.get_key_value(right).expect("Should exist");
+                    }
+                }
+            }
+            count += 1;
+
+            // Can't iterate over starts here because you modify the object you're poining at
+            // in the else block, thus, the condition is true the step before you have put the element
+            // in the map for the final start point
+            all_at_end = finished == num_start;
+        }
+
+        steps_map.values().fold(1, |acc, x| lcm(acc, *x))
+    }
+}
+
+/// Parses a "AAA = (BBB, CCC)" line into the node and its left and right neighbours
+fn parse_node(line: &str) -> Result<(&str, (&str, &str)), ParseError> {
+    let (node, neighbour_str) = line
+        .split_once(" = ")
+        .ok_or_else(|| ParseError::new(line, "<node> = (<left>, <right>)"))?;
+
+    let (left, right) = neighbour_str
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .and_then(|s| s.split_once(", "))
+        .ok_or_else(|| ParseError::at(line, neighbour_str, "(<left>, <right>)"))?;
+
+    Ok((node, (left, right)))
+}
+
+fn parse_network(parser: &mut Parser) -> Result<Network, ParseError> {
+    let input = parser.input();
+    let (instructions_str, graph_str) = input
+        .split_once("\n\n")
+        .ok_or_else(|| parser.error(&input[input.len()..], "a blank line before the nodes"))?;
+
+    if instructions_str.is_empty() {
+        return Err(parser.error(instructions_str, "L or R instructions"));
+    }
+
+    let instructions = instructions_str
+        .char_indices()
+        .map(|(idx, c)| match c {
+            'L' => Ok(0),
+            'R' => Ok(1),
+            _ => Err(parser.error(&instructions_str[idx..idx + c.len_utf8()], "L or R")),
+        })
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    let nodes = parser.lines(graph_str, parse_node)?;
+
+    // Every step has to land on a node, so a neighbour without a line of its own is an error
+    // even in lenient mode
+    let names = nodes.iter().map(|(node, _)| *node).collect::<HashSet<_>>();
+    if let Some(unknown) = nodes
+        .iter()
+        .flat_map(|(_, (left, right))| [*left, *right])
+        .find(|neighbour| !names.contains(neighbour))
+    {
+        return Err(parser.error(unknown, "a node with a line of its own"));
+    }
+
+    let graph = nodes
+        .into_iter()
+        .map(|(node, (left, right))| {
+            (
+                String::from(node),
+                (String::from(left), String::from(right)),
+            )
+        })
+        .collect::<HashMap<String, (String, String)>>();
+
+    Ok(Network {
+        instructions,
+        graph,
+    })
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Network;
+    type Part1 = Unsolved;
+    type Part2 = usize;
+
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        parse_network(parser)
+    }
+
+    fn part1(_input: &Self::Input) -> Self::Part1 {
+        Unsolved
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.ghost_steps()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseMode;
+
+    const EXAMPLE_PART2: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn example_part2() {
+        let input = Day08::parse(EXAMPLE_PART2, ParseMode::Strict).unwrap();
+        assert_eq!(Day08::part2(&input), 6);
+    }
+
+    #[test]
+    fn invalid_node_location() {
+        let input = EXAMPLE_PART2.replace("22B = (22C, 22C)", "22B = 22C, 22C");
+
+        let err = Day08::parse(&input, ParseMode::Strict).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (8, 7, 7));
+    }
+
+    #[test]
+    fn lcm_of_cycles() {
+        assert_eq!(lcm(2, 3), 6);
+        assert_eq!(lcm(4, 6), 12);
+    }
+
+    #[test]
+    fn missing_instructions() {
+        let err = Day08::parse(
+            "\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\n",
+            ParseMode::Lenient,
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "L or R instructions");
+    }
+
+    #[test]
+    fn unknown_neighbour_location() {
+        let err = Day08::parse("LR\n\nAAA = (QQQ, AAA)\n", ParseMode::Lenient).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 8));
+        assert_eq!(err.text, "QQQ");
+    }
+}
@@ -1,19 +1,34 @@
 use std::str::FromStr;
 
+use crate::{
+    parse::{ParseError, Parser},
+    Solution,
+};
+
+pub struct Day09;
+
 #[derive(Debug, Clone)]
-struct Reading {
+pub struct Reading {
     data: Vec<isize>,
     zero_differences: Vec<Vec<isize>>,
 }
 
 impl FromStr for Reading {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let data = s
             .split_whitespace()
-            .filter_map(|reading| reading.parse::<isize>().ok())
-            .collect::<Vec<_>>();
+            .map(|reading| {
+                reading
+                    .parse::<isize>()
+                    .map_err(|_| ParseError::at(s, reading, "a reading"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if data.is_empty() {
+            return Err(ParseError::new(s, "at least one reading"));
+        }
 
         let mut zero_differences = Vec::with_capacity(data.len() - 1);
         let mut zero_diff = zero_difference(&data);
@@ -34,7 +49,7 @@ impl FromStr for Reading {
 }
 
 impl Reading {
-    fn extrapolate(&mut self) {
+    pub fn extrapolate(&mut self) {
         let zero_diffs = self.zero_differences.len();
         let mut current_added_value = 0;
 
@@ -51,7 +66,7 @@ impl Reading {
         self.data.push(current_added_value)
     }
 
-    fn backward_extrapolate(&mut self) {
+    pub fn backward_extrapolate(&mut self) {
         let zero_diffs = self.zero_differences.len();
         let mut current_added_value = 0;
 
@@ -69,11 +84,11 @@ impl Reading {
         self.data.insert(0, current_added_value)
     }
 
-    fn get_last_reading(&self) -> isize {
+    pub fn get_last_reading(&self) -> isize {
         *self.data.last().expect("Data can't be empty")
     }
 
-    fn get_first_reading(&self) -> isize {
+    pub fn get_first_reading(&self) -> isize {
         *self.data.first().expect("Data can't be empty")
     }
 }
@@ -92,33 +107,70 @@ fn is_all_elements_zero(input: &[isize]) -> bool {
     input.iter().all(|element| element == &0)
 }
 
-fn main() -> Result<(), anyhow::Error> {
-    let puzzle_input = std::fs::read_to_string("inputs/day9.txt")?;
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Reading>;
+    type Part1 = isize;
+    type Part2 = isize;
 
-    let mut readings = puzzle_input
-        .split("\n")
-        .filter(|line| !line.is_empty())
-        .filter_map(|line| Reading::from_str(line).ok())
-        .collect::<Vec<_>>();
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        parser.lines(parser.input(), Reading::from_str)
+    }
 
-    let mut readings_cloned = readings.clone();
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let mut readings = input.clone();
 
-    for reading in readings.iter_mut() {
-        reading.extrapolate();
+        for reading in readings.iter_mut() {
+            reading.extrapolate();
+        }
+
+        readings.iter().map(|r| r.get_last_reading()).sum()
     }
 
-    for reading in readings_cloned.iter_mut() {
-        reading.backward_extrapolate();
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let mut readings = input.clone();
+
+        for reading in readings.iter_mut() {
+            reading.backward_extrapolate();
+        }
+
+        readings.iter().map(|r| r.get_first_reading()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseMode;
+
+    const EXAMPLE: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day09::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day09::part1(&input), 114);
     }
 
-    let sum = readings.iter().map(|r| r.get_last_reading()).sum::<isize>();
-    let backsum = readings_cloned
-        .iter()
-        .map(|r| r.get_first_reading())
-        .sum::<isize>();
+    #[test]
+    fn example_part2() {
+        let input = Day09::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(Day09::part2(&input), 2);
+    }
 
-    println!("The sum of extrapolated values is {sum}");
-    println!("The sum of backward extrapolated values is {backsum}");
+    #[test]
+    fn invalid_reading_location() {
+        let err = Day09::parse("0 3 6\n1 3 6.5 10\n", ParseMode::Strict).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (9, 2, 5));
+        assert_eq!(err.text, "6.5");
+    }
 
-    Ok(())
+    #[test]
+    fn negative_readings() {
+        let input = Day09::parse("-1 -2 -3\n", ParseMode::Strict).unwrap();
+        assert_eq!(Day09::part1(&input), -4);
+        assert_eq!(Day09::part2(&input), 0);
+    }
 }
@@ -0,0 +1,61 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+/// Environment variable pointing at a directory containing `dayN.txt` inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayN.txt` in `AOC_INPUT_DIR`, or in the repo's `inputs` directory if it isn't set
+    Default,
+    /// An explicit file
+    Path(PathBuf),
+    /// Standard input, given as `-` on the command line
+    Stdin,
+}
+
+impl From<&str> for InputSource {
+    fn from(value: &str) -> Self {
+        match value {
+            "-" => Self::Stdin,
+            path => Self::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+/// The directory default inputs are read from
+pub fn input_dir() -> PathBuf {
+    match std::env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+    }
+}
+
+pub fn default_input_path(day: u8) -> PathBuf {
+    input_dir().join(format!("day{day}.txt"))
+}
+
+fn read_file(day: u8, path: &Path) -> Result<String, anyhow::Error> {
+    std::fs::read_to_string(path)
+        .with_context(|| format!("Couldn't read input for day {day} from {}", path.display()))
+}
+
+pub fn read_input(day: u8, source: &InputSource) -> Result<String, anyhow::Error> {
+    match source {
+        InputSource::Default => read_file(day, &default_input_path(day)),
+        InputSource::Path(path) => read_file(day, path),
+        InputSource::Stdin => {
+            let mut puzzle_input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut puzzle_input)
+                .with_context(|| format!("Couldn't read input for day {day} from stdin"))?;
+
+            Ok(puzzle_input)
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+pub mod answers;
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod input;
+pub mod parse;
+pub mod runner;
+pub mod scaffold;
+
+use parse::{ParseError, ParseMode, Parser};
+use runner::Day;
+
+/// A puzzle solver for a single day. The input is parsed once and shared by both parts
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Parses the puzzle input, recording any lines left out in lenient mode on the parser
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    fn parse(input: &str, mode: ParseMode) -> Result<Self::Input, ParseError> {
+        Self::parse_with(&mut Parser::new(Self::DAY, input, mode))
+    }
+}
+
+/// Answer for a part that hasn't been solved yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+/// Every solved day, in the order they are run
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+];
+
+pub fn day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,230 @@
+use std::fmt::Display;
+
+/// How lines that fail to parse are treated
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Fail on the first bad line
+    Strict,
+    /// Skip bad lines and carry on with the rest of the input
+    #[default]
+    Lenient,
+}
+
+/// A parse failure, located by line and column in the day's puzzle input
+///
+/// Parsers for a single line or token create errors relative to the text they were given,
+/// and callers move them into place with [`ParseError::within`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// The offending text, empty if the input ended early
+    pub text: String,
+    /// What was expected instead
+    pub expected: String,
+}
+
+/// Byte offset of `inner` in `outer`, or the end of `outer` if it isn't a slice of it
+fn offset(outer: &str, inner: &str) -> usize {
+    let start = outer.as_ptr() as usize;
+    let inner_start = inner.as_ptr() as usize;
+
+    if inner_start >= start && inner_start <= start + outer.len() {
+        inner_start - start
+    } else {
+        outer.len()
+    }
+}
+
+impl ParseError {
+    /// An error at the start of the text being parsed
+    pub fn new(text: &str, expected: impl Into<String>) -> Self {
+        Self {
+            day: 0,
+            line: 1,
+            column: 1,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error at `token`, which is a slice of `within`
+    pub fn at(within: &str, token: &str, expected: impl Into<String>) -> Self {
+        Self::new(token, expected).within(within, token)
+    }
+
+    /// Moves an error in `inner` so it is relative to `outer`, where `inner` is a slice of it
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let offset = offset(outer, inner);
+        let prefix = &outer[..offset];
+        let line_start = prefix.rfind('\n').map_or(0, |idx| idx + 1);
+
+        if self.line == 1 {
+            self.column += prefix[line_start..].chars().count();
+        }
+        self.line += prefix.matches('\n').count();
+
+        self
+    }
+
+    pub fn on_day(mut self, day: u8) -> Self {
+        self.day = day;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} line {} column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, ", found end of input")
+        } else {
+            write!(f, ", found {:?}", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a day's puzzle input, keeping track of the lines left out in lenient mode
+///
+/// Errors made through the parser are located in the whole puzzle input, so sections of it can
+/// be parsed separately without moving their errors into place afterwards.
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    day: u8,
+    input: &'a str,
+    mode: ParseMode,
+    skipped: Vec<ParseError>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(day: u8, input: &'a str, mode: ParseMode) -> Self {
+        Self {
+            day,
+            input,
+            mode,
+            skipped: vec![],
+        }
+    }
+
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    pub fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Why each line left out in lenient mode failed to parse, in input order
+    pub fn skipped(&self) -> &[ParseError] {
+        &self.skipped
+    }
+
+    pub fn into_skipped(self) -> Vec<ParseError> {
+        self.skipped
+    }
+
+    /// An error at `token`, which is a slice of the puzzle input
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        self.locate(ParseError::new(token, expected), token)
+    }
+
+    /// Moves an error in `text`, a slice of the puzzle input, into place
+    pub fn locate(&self, err: ParseError, text: &str) -> ParseError {
+        err.within(self.input, text).on_day(self.day)
+    }
+
+    /// Fails with a located error in strict mode, and records it as skipped in lenient mode
+    pub fn skip(&mut self, err: ParseError) -> Result<(), ParseError> {
+        match self.mode {
+            ParseMode::Strict => Err(err),
+            ParseMode::Lenient => {
+                self.skipped.push(err);
+                Ok(())
+            }
+        }
+    }
+
+    /// Parses every non-empty line of `text`, a slice of the puzzle input. A line that fails
+    /// to parse ends parsing in strict mode, and is skipped and recorded in lenient mode
+    pub fn lines<'t, T>(
+        &mut self,
+        text: &'t str,
+        mut parse_line: impl FnMut(&'t str) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![];
+
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            match parse_line(line) {
+                Ok(item) => items.push(item),
+                Err(err) => self.skip(self.locate(err, line))?,
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_number(line: &str) -> Result<u32, ParseError> {
+        let token = line.trim();
+        token
+            .parse::<u32>()
+            .map_err(|_| ParseError::at(line, token, "a number"))
+    }
+
+    #[test]
+    fn error_location() {
+        let input = "1\n\n  x\n3\n";
+
+        let mut parser = Parser::new(4, input, ParseMode::Strict);
+        let err = parser.lines(input, parse_number).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (4, 3, 3));
+        assert_eq!(err.text, "x");
+        assert_eq!(
+            err.to_string(),
+            "Day 4 line 3 column 3: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn lenient_skips_bad_lines() {
+        let input = "1\nx\n3\n";
+        let mut parser = Parser::new(4, input, ParseMode::Lenient);
+        assert_eq!(parser.lines(input, parse_number).unwrap(), vec![1, 3]);
+
+        let skipped = parser.skipped();
+        assert_eq!(skipped.len(), 1);
+        assert_eq!((skipped[0].line, skipped[0].column), (2, 1));
+        assert_eq!(skipped[0].expected, "a number");
+    }
+
+    #[test]
+    fn sections_are_located_in_the_whole_input() {
+        let input = "header\n\n1\ny\n";
+        let section = &input[8..];
+        let mut parser = Parser::new(4, input, ParseMode::Lenient);
+        assert_eq!(parser.lines(section, parse_number).unwrap(), vec![1]);
+        assert_eq!(parser.skipped()[0].line, 4);
+    }
+
+    #[test]
+    fn nested_errors_keep_their_column() {
+        let line = "Game 1: 3 blue";
+        let set = &line[8..];
+        let err = ParseError::at(set, &set[2..], "a colour").within(line, set);
+        assert_eq!((err.line, err.column), (1, 11));
+    }
+}
@@ -0,0 +1,192 @@
+use std::{
+    fmt::{Display, Write},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    parse::{ParseError, ParseMode, Parser},
+    Solution,
+};
+
+/// Runs the requested parts of a day on its puzzle input
+pub type Runner = fn(&str, &[u8], ParseMode) -> Result<DayRun, anyhow::Error>;
+
+/// A day that can be dispatched to from the runner
+pub struct Day {
+    pub number: u8,
+    pub runner: Runner,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            runner: run::<S>,
+        }
+    }
+
+    pub fn run(
+        &self,
+        puzzle_input: &str,
+        parts: &[u8],
+        mode: ParseMode,
+    ) -> Result<DayRun, anyhow::Error> {
+        (self.runner)(puzzle_input, parts, mode)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: String,
+    /// Name of the part's answer type, like `usize`
+    pub answer_type: &'static str,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartRun>,
+    /// Lines left out of the input in lenient mode, and why
+    pub skipped: Vec<ParseError>,
+}
+
+/// Runs a part, timing only the solve and not formatting its answer
+fn timed<T: Display>(solve: impl FnOnce() -> T) -> (String, &'static str, Duration) {
+    let now = Instant::now();
+    let answer = solve();
+    let elapsed = now.elapsed();
+
+    let type_name = std::any::type_name::<T>();
+    let answer_type = type_name.rsplit("::").next().unwrap_or(type_name);
+
+    (answer.to_string(), answer_type, elapsed)
+}
+
+/// Parses the input once and runs the requested parts, timing each step separately
+pub fn run<S: Solution>(
+    puzzle_input: &str,
+    parts: &[u8],
+    mode: ParseMode,
+) -> Result<DayRun, anyhow::Error> {
+    let day = S::DAY;
+
+    let mut parser = Parser::new(day, puzzle_input, mode);
+
+    let now = Instant::now();
+    let input = S::parse_with(&mut parser)?;
+    let parse_elapsed = now.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, answer_type, elapsed) = match part {
+                1 => timed(|| S::part1(&input)),
+                2 => timed(|| S::part2(&input)),
+                _ => anyhow::bail!("Day {day} has no part {part}"),
+            };
+
+            Ok(PartRun {
+                part,
+                answer,
+                answer_type,
+                elapsed,
+            })
+        })
+        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+    Ok(DayRun {
+        day,
+        parse_elapsed,
+        parts,
+        skipped: parser.into_skipped(),
+    })
+}
+
+pub(crate) fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Formats the runs as a JSON array with one object per part. Answers are always strings so
+/// large values don't lose precision, and durations are in nanoseconds
+pub fn to_json(runs: &[DayRun]) -> String {
+    let objects = runs
+        .iter()
+        .flat_map(|run| {
+            run.parts.iter().map(|part| {
+                format!(
+                    "{{\"day\":{},\"part\":{},\"answer\":{},\"answer_type\":{},\"parse_ns\":{},\"solve_ns\":{}}}",
+                    run.day,
+                    part.part,
+                    json_string(&part.answer),
+                    json_string(part.answer_type),
+                    run.parse_elapsed.as_nanos(),
+                    part.elapsed.as_nanos()
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", objects.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day06::Day06, day09::Day09};
+
+    #[test]
+    fn answer_types() {
+        let day_run = run::<Day06>("Time: 7\nDistance: 9\n", &[1, 2], ParseMode::Strict).unwrap();
+        assert_eq!(day_run.parts[0].answer, "4");
+        assert_eq!(day_run.parts[0].answer_type, "usize");
+        assert!(run::<Day06>("Time: 7\nDistance: 9\n", &[3], ParseMode::Strict).is_err());
+    }
+
+    #[test]
+    fn skipped_lines() {
+        let puzzle_input = "0 3 6\n1 x 3\n\n1 3 6 10\n";
+
+        let day_run = run::<Day09>(puzzle_input, &[1], ParseMode::Lenient).unwrap();
+        assert_eq!(day_run.parts[0].answer, "24");
+        assert_eq!(day_run.skipped.len(), 1);
+        assert_eq!((day_run.skipped[0].line, day_run.skipped[0].column), (2, 3));
+
+        assert!(run::<Day09>(puzzle_input, &[1], ParseMode::Strict).is_err());
+    }
+
+    #[test]
+    fn json_output() {
+        let run = DayRun {
+            day: 7,
+            parse_elapsed: Duration::from_nanos(12),
+            parts: vec![PartRun {
+                part: 2,
+                answer: "say \"hi\"\n".to_string(),
+                answer_type: "Unsolved",
+                elapsed: Duration::from_nanos(3),
+            }],
+            skipped: vec![],
+        };
+
+        assert_eq!(
+            to_json(&[run]),
+            r#"[{"day":7,"part":2,"answer":"say \"hi\"\u000a","answer_type":"Unsolved","parse_ns":12,"solve_ns":3}]"#
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+use crate::input::default_input_path;
+
+/// Module for a new day, with `__NAME__` and `__DAY__` filled in by [`module_source`]
+const TEMPLATE: &str = r#"use crate::{
+    parse::{ParseError, Parser},
+    Solution, Unsolved,
+};
+
+pub struct __NAME__;
+
+impl Solution for __NAME__ {
+    const DAY: u8 = __DAY__;
+    type Input = Vec<String>;
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    fn parse_with(parser: &mut Parser) -> Result<Self::Input, ParseError> {
+        parser.lines(parser.input(), |line| Ok(line.to_string()))
+    }
+
+    fn part1(_input: &Self::Input) -> Self::Part1 {
+        Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Part2 {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseMode;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn example_part1() {
+        let input = __NAME__::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(__NAME__::part1(&input), Unsolved);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = __NAME__::parse(EXAMPLE, ParseMode::Strict).unwrap();
+        assert_eq!(__NAME__::part2(&input), Unsolved);
+    }
+}
+"#;
+
+/// Files written for a new day
+#[derive(Debug, Clone)]
+pub struct NewDay {
+    pub module: PathBuf,
+    /// The empty input file, if there wasn't one already
+    pub input: Option<PathBuf>,
+}
+
+fn module_name(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+/// Source of a new day's module, with empty parts and example tests to fill in
+pub fn module_source(day: u8) -> String {
+    TEMPLATE
+        .replace("__NAME__", &format!("Day{day:02}"))
+        .replace("__DAY__", &day.to_string())
+}
+
+/// Inserts `new_line` among the lines starting with `prefix`, keeping them in order
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, new_line: String) -> Option<()> {
+    let matching = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.trim_start().starts_with(prefix))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    let idx = matching
+        .iter()
+        .find(|&&idx| lines[idx].trim_start() > new_line.trim_start())
+        .copied()
+        .or_else(|| matching.last().map(|idx| idx + 1))?;
+
+    lines.insert(idx, new_line);
+    Some(())
+}
+
+/// Adds the day's module and its entry in `DAYS` to the source of `lib.rs`
+pub fn register(lib_source: &str, day: u8) -> Result<String, anyhow::Error> {
+    let module = module_name(day);
+    let mod_line = format!("pub mod {module};");
+    if lib_source.lines().any(|line| line.trim() == mod_line) {
+        bail!("Day {day} is already registered");
+    }
+
+    let mut lines = lib_source.lines().map(String::from).collect::<Vec<_>>();
+    insert_sorted(&mut lines, "pub mod day", mod_line)
+        .context("Couldn't find the day modules in lib.rs")?;
+    insert_sorted(
+        &mut lines,
+        "Day::new::<day",
+        format!("    Day::new::<{module}::Day{day:02}>(),"),
+    )
+    .context("Couldn't find DAYS in lib.rs")?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Writes the module for a new day into the crate at `root`, registers it with the
+/// dispatcher and creates an empty input for it
+pub fn new_day(root: &Path, day: u8) -> Result<NewDay, anyhow::Error> {
+    let module = root.join("src").join(format!("{}.rs", module_name(day)));
+    if module.exists() {
+        bail!("{} already exists", module.display());
+    }
+
+    let lib_path = root.join("src").join("lib.rs");
+    let lib_source = std::fs::read_to_string(&lib_path)
+        .with_context(|| format!("Couldn't read {}", lib_path.display()))?;
+    let lib_source = register(&lib_source, day)?;
+
+    std::fs::write(&module, module_source(day))
+        .with_context(|| format!("Couldn't write {}", module.display()))?;
+    std::fs::write(&lib_path, lib_source)
+        .with_context(|| format!("Couldn't write {}", lib_path.display()))?;
+
+    let input_path = default_input_path(day);
+    let input = if input_path.exists() {
+        None
+    } else {
+        if let Some(dir) = input_path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Couldn't create {}", dir.display()))?;
+        }
+        std::fs::write(&input_path, "")
+            .with_context(|| format!("Couldn't write {}", input_path.display()))?;
+        Some(input_path)
+    };
+
+    Ok(NewDay { module, input })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod answers;
+pub mod day01;
+pub mod day09;
+pub mod input;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day09::Day09>(),
+];
+";
+
+    #[test]
+    fn registers_in_order() {
+        assert_eq!(
+            register(LIB, 5).unwrap(),
+            "pub mod answers;
+pub mod day01;
+pub mod day05;
+pub mod day09;
+pub mod input;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day09::Day09>(),
+];
+"
+        );
+
+        let lib = register(LIB, 10).unwrap();
+        assert!(lib.contains("pub mod day09;\npub mod day10;\npub mod input;"));
+        assert!(lib.contains("Day::new::<day10::Day10>(),\n];"));
+    }
+
+    #[test]
+    fn already_registered() {
+        assert!(register(LIB, 9).is_err());
+    }
+
+    #[test]
+    fn module_names_the_day() {
+        let source = module_source(12);
+        assert!(source.contains("pub struct Day12;"));
+        assert!(source.contains("const DAY: u8 = 12;"));
+        assert!(!source.contains("__"));
+    }
+}